@@ -17,7 +17,7 @@ fn orbit(mut query: Query<(&mut Transform2d, &Orbit)>, time: Res<Time>) {
 
 fn rotate(mut query: Query<(&mut Transform2d, &Rotate)>, time: Res<Time>) {
     for (mut transform, rotate) in &mut query {
-        transform.rotation += rotate.speed * time.delta_seconds();
+        transform.rotate(rotate.speed * time.delta_seconds());
     }
 }
 
@@ -0,0 +1,118 @@
+use bevy::{math::Affine2, prelude::*};
+
+use crate::transform2d::{Transform2d, Transform2dFromAffineError};
+
+/// Describes the position of an [`Entity`] in 2d space with an arbitrary [`Affine2`],
+/// which unlike [`Transform2d`] can express shear.
+///
+/// This is useful for effects like squash and stretch along a diagonal, or pseudo-perspective card tilts.
+///
+/// A [`Transform`] can't express shear either, so entities with an [`AffineTransform2d`] are pure 2D entities:
+/// they should not have a [`Transform`], and their [`GlobalTransform`] is written directly from their [`GlobalTransform2d`](crate::global_transform2d::GlobalTransform2d).
+/// They can be parented to entities with a [`Transform2d`] or an [`AffineTransform2d`], and their children should be 2D entities as well.
+/// See [`AffineTransform2dBundle`](crate::bundle::AffineTransform2dBundle).
+///
+/// The [`AffineTransform2d`] is never written into a [`Transform`]. If the entity has one anyway it's left stale,
+/// and Bevy's transform propagation gives it and its children with a [`Transform`] the wrong [`GlobalTransform`].
+/// In debug builds a warning is logged for both.
+///
+/// If an entity has both an [`AffineTransform2d`] and a [`Transform2d`], the [`AffineTransform2d`] is used.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct AffineTransform2d {
+    /// The affine transform along the `X` and `Y` axes.
+    pub affine: Affine2,
+    /// The translation along the `Z` axis.
+    ///
+    /// See [`Transform2d::z_translation`].
+    pub z_translation: f32,
+}
+
+impl Default for AffineTransform2d {
+    fn default() -> Self {
+        AffineTransform2d::IDENTITY
+    }
+}
+
+impl AffineTransform2d {
+    /// Creates a new identity [`AffineTransform2d`].
+    pub const IDENTITY: Self = AffineTransform2d {
+        affine: Affine2::IDENTITY,
+        z_translation: 0.,
+    };
+
+    /// Creates a new [`AffineTransform2d`] from an [`Affine2`].
+    ///
+    /// `z_translation` will be `0.`.
+    #[inline]
+    pub const fn from_affine(affine: Affine2) -> Self {
+        AffineTransform2d {
+            affine,
+            z_translation: 0.,
+        }
+    }
+
+    /// Creates a new [`AffineTransform2d`] that shears along the `X` axis by `x` and along the `Y` axis by `y`.
+    ///
+    /// A point `(px, py)` is moved to `(px + x * py, py + y * px)`.
+    #[inline]
+    pub fn from_shear(x: f32, y: f32) -> Self {
+        AffineTransform2d::from_affine(Affine2::from_mat2(Mat2::from_cols(
+            Vec2::new(1., y),
+            Vec2::new(x, 1.),
+        )))
+    }
+
+    /// Returns this [`AffineTransform2d`] with a new Z translation.
+    #[must_use]
+    #[inline]
+    pub fn with_z_translation(mut self, z_translation: f32) -> Self {
+        self.z_translation = z_translation;
+        self
+    }
+
+    /// Transforms the given `point`, applying shear, scale, rotation and translation.
+    /// `z_translation` is ignored.
+    #[inline]
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.affine.transform_point2(point)
+    }
+
+    /// Returns this transform as a [`Transform2d`].
+    ///
+    /// Returns an error if the transform contains shear, see [`Transform2d::try_from_affine`].
+    #[inline]
+    pub fn try_to_transform_2d(&self) -> Result<Transform2d, Transform2dFromAffineError> {
+        Transform2d::try_from_affine(self.affine)
+            .map(|transform| transform.with_z_translation(self.z_translation))
+    }
+}
+
+impl From<Transform2d> for AffineTransform2d {
+    #[inline]
+    fn from(transform: Transform2d) -> Self {
+        AffineTransform2d {
+            affine: transform.compute_affine(),
+            z_translation: transform.z_translation,
+        }
+    }
+}
+
+impl From<Affine2> for AffineTransform2d {
+    #[inline]
+    fn from(affine: Affine2) -> Self {
+        AffineTransform2d::from_affine(affine)
+    }
+}
+
+impl TryFrom<AffineTransform2d> for Transform2d {
+    type Error = Transform2dFromAffineError;
+
+    /// Equivalent to [`AffineTransform2d::try_to_transform_2d`].
+    #[inline]
+    fn try_from(transform: AffineTransform2d) -> Result<Self, Self::Error> {
+        transform.try_to_transform_2d()
+    }
+}
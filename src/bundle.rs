@@ -1,10 +1,15 @@
 use bevy::prelude::*;
 
-use crate::transform2d::Transform2d;
+use crate::{
+    affine_transform2d::AffineTransform2d, global_transform2d::GlobalTransform2d,
+    transform2d::Transform2d,
+};
 
 #[derive(Bundle, Clone, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform2dBundle {
     pub transform: Transform2d,
+    pub global_transform_2d: GlobalTransform2d,
     pub transform_3d: Transform,
     pub global_transform: GlobalTransform,
 }
@@ -13,6 +18,7 @@ impl Transform2dBundle {
     /// Creates a new identity [`TransformBundle`], with no translation, rotation, and a scale of 1 on all axes.
     pub const IDENTITY: Self = Transform2dBundle {
         transform: Transform2d::IDENTITY,
+        global_transform_2d: GlobalTransform2d::IDENTITY,
         transform_3d: Transform::IDENTITY,
         global_transform: GlobalTransform::IDENTITY,
     };
@@ -33,6 +39,82 @@ impl From<Transform2d> for Transform2dBundle {
     }
 }
 
+/// A [`Bundle`] for pure 2D entities, which have no [`Transform`].
+///
+/// The [`GlobalTransform`] of these entities is written directly from their [`GlobalTransform2d`],
+/// skipping both the synchronisation into [`Transform`] and Bevy's 3D transform propagation.
+///
+/// Pure 2D entities can be parented to entities with a [`Transform2d`] (pure 2D or not), but not to entities with only a [`Transform`].
+///
+/// Bevy's transform propagation stops at pure 2D entities, so children with a [`Transform`] don't get an updated [`GlobalTransform`].
+/// Give every child of a pure 2D entity a [`Transform2d`] and no [`Transform`] as well. In debug builds a warning is logged otherwise.
+#[derive(Bundle, Clone, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pure2dBundle {
+    pub transform: Transform2d,
+    pub global_transform_2d: GlobalTransform2d,
+    pub global_transform: GlobalTransform,
+}
+
+impl Pure2dBundle {
+    /// Creates a new identity [`Pure2dBundle`], with no translation, rotation, and a scale of 1 on all axes.
+    pub const IDENTITY: Self = Pure2dBundle {
+        transform: Transform2d::IDENTITY,
+        global_transform_2d: GlobalTransform2d::IDENTITY,
+        global_transform: GlobalTransform::IDENTITY,
+    };
+
+    /// Creates a new [`Pure2dBundle`] from a [`Transform2d`].
+    pub const fn from_transform(transform: Transform2d) -> Self {
+        Pure2dBundle {
+            transform,
+            ..Pure2dBundle::IDENTITY
+        }
+    }
+}
+
+impl From<Transform2d> for Pure2dBundle {
+    #[inline]
+    fn from(transform: Transform2d) -> Self {
+        Pure2dBundle::from_transform(transform)
+    }
+}
+
+/// A [`Bundle`] for entities with an [`AffineTransform2d`].
+///
+/// Like the [`Pure2dBundle`] this has no [`Transform`], as a [`Transform`] can't represent shear.
+#[derive(Bundle, Clone, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AffineTransform2dBundle {
+    pub transform: AffineTransform2d,
+    pub global_transform_2d: GlobalTransform2d,
+    pub global_transform: GlobalTransform,
+}
+
+impl AffineTransform2dBundle {
+    /// Creates a new identity [`AffineTransform2dBundle`].
+    pub const IDENTITY: Self = AffineTransform2dBundle {
+        transform: AffineTransform2d::IDENTITY,
+        global_transform_2d: GlobalTransform2d::IDENTITY,
+        global_transform: GlobalTransform::IDENTITY,
+    };
+
+    /// Creates a new [`AffineTransform2dBundle`] from an [`AffineTransform2d`].
+    pub const fn from_transform(transform: AffineTransform2d) -> Self {
+        AffineTransform2dBundle {
+            transform,
+            ..AffineTransform2dBundle::IDENTITY
+        }
+    }
+}
+
+impl From<AffineTransform2d> for AffineTransform2dBundle {
+    #[inline]
+    fn from(transform: AffineTransform2d) -> Self {
+        AffineTransform2dBundle::from_transform(transform)
+    }
+}
+
 /// A [`Bundle`] with the following [`Component`](bevy_ecs::component::Component)s:
 /// * [`Visibility`] and [`ComputedVisibility`], which describe the visibility of an entity
 /// * [`Transform`] and [`GlobalTransform`], which describe the position of an entity
@@ -54,6 +136,8 @@ pub struct Spatial2dBundle {
     pub view_visibility: ViewVisibility,
     /// The transform of the entity.
     pub transform: Transform2d,
+    /// The global 2D transform of the entity.
+    pub global_transform_2d: GlobalTransform2d,
     /// The 3D transform of the entity.
     pub transform_3d: Transform,
     /// The global transform of the entity.
@@ -79,6 +163,7 @@ impl Spatial2dBundle {
         inherited_visibility: InheritedVisibility::HIDDEN,
         view_visibility: ViewVisibility::HIDDEN,
         transform: Transform2d::IDENTITY,
+        global_transform_2d: GlobalTransform2d::IDENTITY,
         transform_3d: Transform::IDENTITY,
         global_transform: GlobalTransform::IDENTITY,
     };
@@ -0,0 +1,520 @@
+use std::{
+    f64::consts::{PI, TAU},
+    ops::{Mul, MulAssign},
+};
+
+use bevy::{
+    math::{DAffine2, DMat2, DMat3, DVec2, DVec3},
+    prelude::*,
+};
+
+use crate::transform2d::{
+    decompose_affine, Transform2d, Transform2dFromAffineError, AFFINE_EPSILON,
+};
+
+/// Describes the position of an [`Entity`] in 2d space with double precision.
+///
+/// Use this instead of [`Transform2d`] in large worlds, where the precision of an `f32` translation
+/// isn't enough and entities far from the origin start to jitter.
+///
+/// This component acts as a proxy to the [`Transform2d`] component, and thus *requires* that a [`Transform2d`] is present to function.
+/// The [`Transform2d`] of an entity without a [`Parent`] is written relative to the [`FloatingOrigin2d`](crate::floating_origin::FloatingOrigin2d),
+/// so rendering stays precise near the origin, which should be kept close to the camera.
+/// The [`Transform2d`] of an entity with a [`Parent`] is relative to the parent as usual.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct DTransform2d {
+    /// The translation along the `X` and `Y` axes.
+    pub translation: DVec2,
+    /// The rotation in radians. Positive values rotate anti-clockwise.
+    pub rotation: f64,
+    /// The scale along the `X` and `Y` axes.
+    pub scale: DVec2,
+    /// The translation along the `Z` axis.
+    ///
+    /// See [`Transform2d::z_translation`].
+    pub z_translation: f32,
+}
+
+impl Default for DTransform2d {
+    fn default() -> Self {
+        DTransform2d::IDENTITY
+    }
+}
+
+impl DTransform2d {
+    /// Creates a new identity [`DTransform2d`], with no translation, rotation, and a scale of 1 on all axes.
+    ///
+    /// Translation is `DVec2::ZERO`, rotation is `0.`, scale is `DVec2::ONE` and `z_translation` is `0.`.
+    pub const IDENTITY: Self = DTransform2d {
+        translation: DVec2::ZERO,
+        rotation: 0.,
+        scale: DVec2::ONE,
+        z_translation: 0.,
+    };
+
+    /// Creates a new [`DTransform2d`] at the position `(x, y)`.
+    ///
+    /// Rotation will be `0.`, scale will be `DVec2::ONE` and `z_translation` will be `0.`.
+    #[inline]
+    pub fn from_xy(x: f64, y: f64) -> Self {
+        DTransform2d::from_translation(DVec2::new(x, y))
+    }
+
+    /// Creates a new [`DTransform2d`] at the position `(x, y)`, with a `z_translation` of `z`.
+    #[inline]
+    pub fn from_xyz(x: f64, y: f64, z: f32) -> Self {
+        Self::from_translation(DVec2::new(x, y)).with_z_translation(z)
+    }
+
+    /// Creates a new [`DTransform2d`] with `translation`.
+    ///
+    /// Rotation will be `0.`, scale will be `DVec2::ONE` and `z_translation` will be `0.`.
+    #[inline]
+    pub fn from_translation(translation: DVec2) -> Self {
+        DTransform2d {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`DTransform2d`] with `translation`, using its `z` as the `z_translation`.
+    ///
+    /// Rotation will be `0.` and scale will be `DVec2::ONE`.
+    #[inline]
+    pub fn from_translation_3d(DVec3 { x, y, z }: DVec3) -> Self {
+        DTransform2d {
+            translation: DVec2 { x, y },
+            z_translation: z as f32,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`DTransform2d`] with `rotation`.
+    ///
+    /// Translation will be `DVec2::ZERO`, scale will be `DVec2::ONE` and `z_translation` will be `0.`.
+    #[inline]
+    pub fn from_rotation(rotation: f64) -> Self {
+        DTransform2d {
+            rotation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`DTransform2d`] with `scale`.
+    ///
+    /// Translation will be `DVec2::ZERO`, rotation will be `0.` and `z_translation` will be `0.`
+    #[inline]
+    pub fn from_scale(scale: DVec2) -> Self {
+        DTransform2d {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns this [`DTransform2d`] with a new translation.
+    #[must_use]
+    #[inline]
+    pub fn with_translation(mut self, translation: DVec2) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns this [`DTransform2d`] with a new rotation.
+    #[must_use]
+    #[inline]
+    pub fn with_rotation(mut self, rotation: f64) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns this [`DTransform2d`] with a new scale.
+    #[must_use]
+    #[inline]
+    pub fn with_scale(mut self, scale: DVec2) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns this [`DTransform2d`] with a new Z translation.
+    #[must_use]
+    #[inline]
+    pub fn with_z_translation(mut self, z_translation: f32) -> Self {
+        self.z_translation = z_translation;
+        self
+    }
+
+    /// Returns this [`DTransform2d`] rotated so the local `direction` points in the given `target_direction`.
+    #[inline]
+    pub fn pointed_to(mut self, direction: DVec2, target_direction: DVec2) -> Self {
+        self.point_to(direction, target_direction);
+        self
+    }
+
+    /// Returns this [`DTransform2d`] rotated so the local `direction` points at the given `target_position`.
+    #[inline]
+    pub fn pointed_at(mut self, direction: DVec2, target_position: DVec2) -> Self {
+        self.point_at(direction, target_position);
+        self
+    }
+
+    /// Rotates this [`DTransform2d`] so the local `direction` points in the given `target_direction`.
+    #[inline]
+    pub fn point_to(&mut self, direction: DVec2, target_direction: DVec2) {
+        self.rotation = direction
+            .perp_dot(target_direction)
+            .atan2(direction.dot(target_direction));
+    }
+
+    /// Rotates this [`DTransform2d`] so the local `direction` points at the given `target_position`.
+    #[inline]
+    pub fn point_at(&mut self, direction: DVec2, target_position: DVec2) {
+        self.point_to(direction, target_position - self.translation);
+    }
+
+    /// Get the unit vector in the local `X` direction.
+    #[inline]
+    pub fn local_x(&self) -> DVec2 {
+        let (sin, cos) = self.rotation.sin_cos();
+        (cos, sin).into()
+    }
+
+    /// Equivalent to [`-local_x()`][Self::local_x()]
+    #[inline]
+    pub fn left(&self) -> DVec2 {
+        -self.local_x()
+    }
+
+    /// Equivalent to [`local_x()`][Self::local_x()]
+    #[inline]
+    pub fn right(&self) -> DVec2 {
+        self.local_x()
+    }
+
+    /// Get the unit vector in the local `Y` direction.
+    #[inline]
+    pub fn local_y(&self) -> DVec2 {
+        let (sin, cos) = self.rotation.sin_cos();
+        (-sin, cos).into()
+    }
+
+    /// Equivalent to [`local_y()`][Self::local_y]
+    #[inline]
+    pub fn up(&self) -> DVec2 {
+        self.local_y()
+    }
+
+    /// Equivalent to [`-local_y()`][Self::local_y]
+    #[inline]
+    pub fn down(&self) -> DVec2 {
+        -self.local_y()
+    }
+
+    /// Returns the rotation matrix from this transforms rotation.
+    #[inline]
+    pub fn rotation_matrix(&self) -> DMat2 {
+        DMat2::from_angle(self.rotation)
+    }
+
+    /// Computes the affine transformation matrix of this transform.
+    #[inline]
+    pub fn compute_matrix(&self) -> DMat3 {
+        DMat3::from_scale_angle_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Computes the affine transform of this transform.
+    #[inline]
+    pub fn compute_affine(&self) -> DAffine2 {
+        DAffine2::from_scale_angle_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Computes the inverse of the affine transform of this transform.
+    ///
+    /// Unlike [`try_inverse()`][Self::try_inverse] this works for any non-zero scale.
+    #[inline]
+    pub fn compute_inverse_affine(&self) -> DAffine2 {
+        let matrix2 = DMat2::from_diagonal(self.scale.recip()) * DMat2::from_angle(-self.rotation);
+        DAffine2 {
+            matrix2,
+            translation: -(matrix2 * self.translation),
+        }
+    }
+
+    /// Decomposes `affine` into translation, rotation and scale.
+    ///
+    /// Mirroring is represented by a negative `scale.x`, and `z_translation` will be `0.`.
+    ///
+    /// Returns an error if `affine` is degenerate or contains shear, which a [`DTransform2d`] can't represent.
+    /// Shear within a small tolerance, such as from rounding errors, is discarded.
+    pub fn try_from_affine(affine: DAffine2) -> Result<Self, Transform2dFromAffineError> {
+        let (translation, rotation, scale) = decompose_affine!(affine, f64)?;
+        Ok(DTransform2d {
+            translation,
+            rotation,
+            scale,
+            z_translation: 0.,
+        })
+    }
+
+    /// Translates this [`DTransform2d`] around a `point` in space.
+    #[inline]
+    pub fn translate_around(&mut self, point: DVec2, angle: f64) {
+        self.translation = point + DMat2::from_angle(angle) * (self.translation - point);
+    }
+
+    /// Rotates this [`DTransform2d`] by `angle`.
+    ///
+    /// The resulting rotation is wrapped into the range `(-PI, PI]`, so rotating every frame won't make it grow without bound.
+    #[inline]
+    pub fn rotate(&mut self, angle: f64) {
+        self.rotation = PI - (PI - (self.rotation + angle)).rem_euclid(TAU);
+    }
+
+    /// Rotates this [`DTransform2d`] around a `point` in space.
+    ///
+    /// Unlike [`rotate()`][Self::rotate] the resulting rotation is not wrapped.
+    #[inline]
+    pub fn rotate_around(&mut self, point: DVec2, angle: f64) {
+        self.translate_around(point, angle);
+        self.rotation += angle;
+    }
+
+    /// Transforms the given `point`, applying scale, rotation and translation.
+    /// `z_translation` is ignored.
+    #[inline]
+    pub fn transform_point(&self, mut point: DVec2) -> DVec2 {
+        point *= self.scale;
+        point = self.rotation_matrix() * point;
+        point += self.translation;
+        point
+    }
+
+    /// Transforms the given `point` by the inverse of this [`DTransform2d`],
+    /// undoing translation, rotation and scale.
+    /// `z_translation` is ignored.
+    #[inline]
+    pub fn inverse_transform_point(&self, mut point: DVec2) -> DVec2 {
+        point -= self.translation;
+        point = self.rotation_matrix().transpose() * point;
+        point /= self.scale;
+        point
+    }
+
+    /// Transforms the given `vector`, applying scale and rotation.
+    /// Translation and `z_translation` are ignored.
+    #[inline]
+    pub fn transform_vector(&self, vector: DVec2) -> DVec2 {
+        self.rotation_matrix() * (vector * self.scale)
+    }
+
+    /// Transforms the given `vector` by the inverse of this [`DTransform2d`],
+    /// undoing rotation and scale.
+    /// Translation and `z_translation` are ignored.
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: DVec2) -> DVec2 {
+        (self.rotation_matrix().transpose() * vector) / self.scale
+    }
+
+    /// Returns the inverse of this [`DTransform2d`], including `z_translation`.
+    ///
+    /// Like [`Transform2d::try_inverse`], this returns an error if the inverse contains shear or the scale is zero along an axis.
+    /// Use [`inverse_transform_point()`][Self::inverse_transform_point] or
+    /// [`compute_inverse_affine()`][Self::compute_inverse_affine] in that case.
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Self, Transform2dFromAffineError> {
+        DTransform2d::try_from_affine(self.compute_inverse_affine())
+            .map(|inverse| inverse.with_z_translation(-self.z_translation))
+    }
+
+    /// Multiplies `self` with `transform` component by component, returning the
+    /// resulting [`DTransform2d`]
+    #[inline]
+    #[must_use]
+    pub fn mul_transform(&self, transform: DTransform2d) -> Self {
+        DTransform2d {
+            translation: self.transform_point(transform.translation),
+            rotation: self.rotation + transform.rotation,
+            scale: self.scale * transform.scale,
+            z_translation: self.z_translation + transform.z_translation,
+        }
+    }
+
+    /// Interpolates between `self` and `other` by `t`, returning the resulting [`DTransform2d`].
+    ///
+    /// Translation, scale and `z_translation` are interpolated linearly,
+    /// rotation is interpolated along the shortest arc, like [`Transform2d::lerp`].
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, other: DTransform2d, t: f64) -> Self {
+        let rotation_delta = (other.rotation - self.rotation + PI).rem_euclid(TAU) - PI;
+        DTransform2d {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation + rotation_delta * t,
+            scale: self.scale.lerp(other.scale, t),
+            z_translation: self.z_translation
+                + (other.z_translation - self.z_translation) * t as f32,
+        }
+    }
+
+    /// Returns this transform as a [`Transform2d`] with its translation relative to `origin`.
+    ///
+    /// The subtraction is done with double precision,
+    /// so the result is precise as long as this transform is close to `origin`.
+    #[inline]
+    pub fn to_transform_2d_relative_to(&self, origin: DVec2) -> Transform2d {
+        Transform2d {
+            translation: (self.translation - origin).as_vec2(),
+            rotation: self.rotation as f32,
+            scale: self.scale.as_vec2(),
+            z_translation: self.z_translation,
+        }
+    }
+}
+
+impl From<Transform2d> for DTransform2d {
+    #[inline]
+    fn from(transform: Transform2d) -> Self {
+        DTransform2d {
+            translation: transform.translation.as_dvec2(),
+            rotation: transform.rotation as f64,
+            scale: transform.scale.as_dvec2(),
+            z_translation: transform.z_translation,
+        }
+    }
+}
+
+impl From<DTransform2d> for Transform2d {
+    #[inline]
+    fn from(transform: DTransform2d) -> Self {
+        transform.to_transform_2d_relative_to(DVec2::ZERO)
+    }
+}
+
+impl TryFrom<DAffine2> for DTransform2d {
+    type Error = Transform2dFromAffineError;
+
+    /// Equivalent to [`DTransform2d::try_from_affine`].
+    #[inline]
+    fn try_from(affine: DAffine2) -> Result<Self, Self::Error> {
+        DTransform2d::try_from_affine(affine)
+    }
+}
+
+impl TryFrom<DMat3> for DTransform2d {
+    type Error = Transform2dFromAffineError;
+
+    /// Decomposes `matrix` into translation, rotation and scale.
+    ///
+    /// Returns an error if `matrix` is not affine or contains shear.
+    fn try_from(matrix: DMat3) -> Result<Self, Self::Error> {
+        if !matrix.row(2).abs_diff_eq(DVec3::Z, AFFINE_EPSILON as f64) {
+            return Err(Transform2dFromAffineError::NotAffine);
+        }
+        DTransform2d::try_from(DAffine2::from_mat3(matrix))
+    }
+}
+
+impl Mul<DTransform2d> for DTransform2d {
+    type Output = DTransform2d;
+
+    #[inline]
+    fn mul(self, transform: DTransform2d) -> Self::Output {
+        self.mul_transform(transform)
+    }
+}
+
+impl MulAssign<DTransform2d> for DTransform2d {
+    #[inline]
+    fn mul_assign(&mut self, transform: DTransform2d) {
+        *self = self.mul_transform(transform);
+    }
+}
+
+impl Mul<DVec2> for DTransform2d {
+    type Output = DVec2;
+
+    #[inline]
+    fn mul(self, value: DVec2) -> Self::Output {
+        self.transform_point(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_to_origin_keeps_precision() {
+        let transform = DTransform2d::from_xy(10_000_000.25, -30_000_000.5);
+
+        // Far from the origin an `f32` can't represent the fractional part.
+        assert_ne!(Transform2d::from(transform).translation.x.fract(), 0.25);
+
+        let relative = transform.to_transform_2d_relative_to(DVec2::new(10_000_000., -30_000_000.));
+        assert_eq!(relative.translation, Vec2::new(0.25, -0.5));
+    }
+
+    #[test]
+    fn inverse() {
+        let transform = DTransform2d::from_xyz(10_000_000.5, -3., 2.)
+            .with_rotation(0.7)
+            .with_scale(DVec2::splat(2.));
+        let point = DVec2::new(4., -5.);
+
+        assert!(transform
+            .try_inverse()
+            .unwrap()
+            .transform_point(transform.transform_point(point))
+            .abs_diff_eq(point, 1e-9));
+        assert!((transform * transform.try_inverse().unwrap())
+            .translation
+            .abs_diff_eq(DVec2::ZERO, 1e-9));
+        assert!(transform
+            .inverse_transform_vector(transform.transform_vector(point))
+            .abs_diff_eq(point, 1e-12));
+    }
+
+    #[test]
+    fn try_from_affine() {
+        let transform = DTransform2d::from_xy(10_000_000.5, -3.)
+            .with_rotation(-2.)
+            .with_scale(DVec2::new(-1., 3.));
+        let decomposed = DTransform2d::try_from(transform.compute_matrix()).unwrap();
+        assert!(decomposed
+            .compute_affine()
+            .abs_diff_eq(transform.compute_affine(), 1e-9));
+
+        let shear = DAffine2::from_cols_array(&[1., 0., 1., 1., 0., 0.]);
+        assert!(matches!(
+            DTransform2d::try_from(shear),
+            Err(Transform2dFromAffineError::Shear { .. })
+        ));
+    }
+
+    #[test]
+    fn pointed_at() {
+        let transform = DTransform2d::from_translation_3d(DVec3::new(10_000_000., 0., 2.))
+            .pointed_at(DVec2::Y, DVec2::new(10_000_000., -5.));
+        assert_eq!(transform.z_translation, 2.);
+        assert!(transform.up().abs_diff_eq(DVec2::NEG_Y, 1e-12));
+
+        let transform = DTransform2d::IDENTITY.pointed_to(DVec2::X, DVec2::new(1., 1.));
+        assert!(transform.right().abs_diff_eq(DVec2::ONE.normalize(), 1e-12));
+    }
+
+    #[test]
+    fn rotate_wraps() {
+        let mut transform = DTransform2d::from_rotation(3.);
+        transform.rotate(1.);
+        assert!((transform.rotation - (4. - TAU)).abs() < 1e-12);
+
+        // `rotate_around` doesn't wrap.
+        transform.rotate_around(DVec2::ZERO, TAU);
+        assert!((transform.rotation - 4.).abs() < 1e-12);
+
+        let lerped = DTransform2d::from_rotation(3.).lerp(DTransform2d::from_rotation(-3.), 0.5);
+        assert!((lerped.rotation - PI).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,408 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use bevy::prelude::*;
+
+/// A signed fixed-point number with 16 fractional bits.
+///
+/// All arithmetic is done on integers, so the results are bit-identical on every machine.
+/// Use it for simulations that have to stay in lockstep across machines, like rollback netcode.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct FixedPoint(i64);
+
+impl FixedPoint {
+    /// The number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+    /// `0`.
+    pub const ZERO: Self = FixedPoint(0);
+    /// `1`.
+    pub const ONE: Self = FixedPoint(1 << Self::FRAC_BITS);
+
+    /// Creates a [`FixedPoint`] from its raw bits, `value * 2^16`.
+    #[inline]
+    pub const fn from_bits(bits: i64) -> Self {
+        FixedPoint(bits)
+    }
+
+    /// Returns the raw bits of this number, `value * 2^16`.
+    #[inline]
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Creates a [`FixedPoint`] from an integer.
+    #[inline]
+    pub const fn from_int(value: i32) -> Self {
+        FixedPoint((value as i64) << Self::FRAC_BITS)
+    }
+
+    /// Creates a [`FixedPoint`] from an `f32`, rounding to the nearest representable value.
+    ///
+    /// This only uses exact `f32` operations, so the result is the same on every machine.
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        FixedPoint((value * Self::ONE.0 as f32).round() as i64)
+    }
+
+    /// Returns this number as an `f32`. Meant for rendering, not for simulation.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    /// Returns the absolute value of this number.
+    #[inline]
+    pub const fn abs(self) -> Self {
+        FixedPoint(self.0.abs())
+    }
+}
+
+impl Add for FixedPoint {
+    type Output = FixedPoint;
+
+    #[inline]
+    fn add(self, rhs: FixedPoint) -> Self::Output {
+        FixedPoint(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for FixedPoint {
+    #[inline]
+    fn add_assign(&mut self, rhs: FixedPoint) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for FixedPoint {
+    type Output = FixedPoint;
+
+    #[inline]
+    fn sub(self, rhs: FixedPoint) -> Self::Output {
+        FixedPoint(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for FixedPoint {
+    #[inline]
+    fn sub_assign(&mut self, rhs: FixedPoint) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for FixedPoint {
+    type Output = FixedPoint;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        FixedPoint(-self.0)
+    }
+}
+
+impl Mul for FixedPoint {
+    type Output = FixedPoint;
+
+    /// Multiplies two numbers, rounding towards negative infinity.
+    #[inline]
+    fn mul(self, rhs: FixedPoint) -> Self::Output {
+        FixedPoint(((self.0 as i128 * rhs.0 as i128) >> Self::FRAC_BITS) as i64)
+    }
+}
+
+impl Div for FixedPoint {
+    type Output = FixedPoint;
+
+    /// Divides two numbers, rounding towards zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[inline]
+    fn div(self, rhs: FixedPoint) -> Self::Output {
+        FixedPoint((((self.0 as i128) << Self::FRAC_BITS) / rhs.0 as i128) as i64)
+    }
+}
+
+/// A 2D vector of [`FixedPoint`] numbers.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct FixedVec2 {
+    pub x: FixedPoint,
+    pub y: FixedPoint,
+}
+
+impl FixedVec2 {
+    /// All zeroes.
+    pub const ZERO: Self = FixedVec2::new(FixedPoint::ZERO, FixedPoint::ZERO);
+    /// All ones.
+    pub const ONE: Self = FixedVec2::new(FixedPoint::ONE, FixedPoint::ONE);
+    /// A unit vector pointing along the positive `X` axis.
+    pub const X: Self = FixedVec2::new(FixedPoint::ONE, FixedPoint::ZERO);
+    /// A unit vector pointing along the positive `Y` axis.
+    pub const Y: Self = FixedVec2::new(FixedPoint::ZERO, FixedPoint::ONE);
+
+    /// Creates a new [`FixedVec2`].
+    #[inline]
+    pub const fn new(x: FixedPoint, y: FixedPoint) -> Self {
+        FixedVec2 { x, y }
+    }
+
+    /// Creates a new [`FixedVec2`] from integers.
+    #[inline]
+    pub const fn from_ints(x: i32, y: i32) -> Self {
+        FixedVec2::new(FixedPoint::from_int(x), FixedPoint::from_int(y))
+    }
+
+    /// Creates a new [`FixedVec2`] from a [`Vec2`], see [`FixedPoint::from_f32`].
+    #[inline]
+    pub fn from_vec2(vector: Vec2) -> Self {
+        FixedVec2::new(
+            FixedPoint::from_f32(vector.x),
+            FixedPoint::from_f32(vector.y),
+        )
+    }
+
+    /// Returns this vector as a [`Vec2`]. Meant for rendering, not for simulation.
+    #[inline]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.x.to_f32(), self.y.to_f32())
+    }
+
+    /// Returns the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot(self, rhs: FixedVec2) -> FixedPoint {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// Rotates this vector by `angle`.
+    #[inline]
+    pub fn rotate(self, angle: FixedAngle) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        FixedVec2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+}
+
+impl Add for FixedVec2 {
+    type Output = FixedVec2;
+
+    #[inline]
+    fn add(self, rhs: FixedVec2) -> Self::Output {
+        FixedVec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for FixedVec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: FixedVec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for FixedVec2 {
+    type Output = FixedVec2;
+
+    #[inline]
+    fn sub(self, rhs: FixedVec2) -> Self::Output {
+        FixedVec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for FixedVec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: FixedVec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for FixedVec2 {
+    type Output = FixedVec2;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        FixedVec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul for FixedVec2 {
+    type Output = FixedVec2;
+
+    /// Multiplies two vectors component by component.
+    #[inline]
+    fn mul(self, rhs: FixedVec2) -> Self::Output {
+        FixedVec2::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+impl Mul<FixedPoint> for FixedVec2 {
+    type Output = FixedVec2;
+
+    #[inline]
+    fn mul(self, rhs: FixedPoint) -> Self::Output {
+        FixedVec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// An angle stored as a fraction of a full turn, with `65536` steps per turn.
+///
+/// Adding angles wraps around, and the sine and cosine are read from a lookup table,
+/// so the results are bit-identical on every machine.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct FixedAngle(u16);
+
+/// `PI * 2^32`, rounded to the nearest integer.
+const PI_Q32: i128 = 13_493_037_705;
+
+/// The number of entries in a quarter turn of [`SIN_TABLE`], excluding the last one.
+const SIN_TABLE_QUARTER: usize = 1024;
+
+/// The sine of every `1 / 4096` of a turn from `0` to a quarter turn, as the raw bits of a [`FixedPoint`].
+///
+/// The table is computed by the compiler with integer arithmetic only.
+const SIN_TABLE: [i32; SIN_TABLE_QUARTER + 1] = {
+    let mut table = [0; SIN_TABLE_QUARTER + 1];
+    let mut i = 0;
+    while i <= SIN_TABLE_QUARTER {
+        let x = PI_Q32 * i as i128 / (2 * SIN_TABLE_QUARTER as i128);
+        table[i] = ((sin_q32(x) + (1 << 15)) >> 16) as i32;
+        i += 1;
+    }
+    table
+};
+
+/// The Taylor series of the sine of `x`, with `x` and the result in fixed-point with 32 fractional bits.
+const fn sin_q32(x: i128) -> i128 {
+    let x2 = (x * x) >> 32;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1;
+    while n < 12 {
+        term = -((term * x2) >> 32) / ((2 * n) * (2 * n + 1));
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+impl FixedAngle {
+    /// No rotation.
+    pub const ZERO: Self = FixedAngle(0);
+    /// A quarter turn anti-clockwise.
+    pub const QUARTER_TURN: Self = FixedAngle(1 << 14);
+    /// A half turn.
+    pub const HALF_TURN: Self = FixedAngle(1 << 15);
+    /// A quarter turn clockwise.
+    pub const THREE_QUARTER_TURN: Self = FixedAngle(3 << 14);
+
+    /// Creates a [`FixedAngle`] from its raw bits, where `65536` is a full turn.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        FixedAngle(bits)
+    }
+
+    /// Returns the raw bits of this angle, where `65536` is a full turn.
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Creates a [`FixedAngle`] from an angle in radians, rounding to the nearest step.
+    ///
+    /// This only uses basic `f32` arithmetic, so the result is the same on every machine.
+    #[inline]
+    pub fn from_radians(radians: f32) -> Self {
+        let turns = radians / std::f32::consts::TAU;
+        FixedAngle(((turns - turns.floor()) * 65536.).round() as u32 as u16)
+    }
+
+    /// Returns this angle in radians, in the range `[0, TAU)`. Meant for rendering, not for simulation.
+    #[inline]
+    pub fn to_radians(self) -> f32 {
+        self.0 as f32 / 65536. * std::f32::consts::TAU
+    }
+
+    /// Returns the sine of this angle.
+    #[inline]
+    pub fn sin(self) -> FixedPoint {
+        let within = self.0 & 0x3FFF;
+        let value = match self.0 >> 14 {
+            0 => Self::quarter_sin(within),
+            1 => Self::quarter_sin(0x4000 - within),
+            2 => -Self::quarter_sin(within),
+            _ => -Self::quarter_sin(0x4000 - within),
+        };
+        FixedPoint::from_bits(value as i64)
+    }
+
+    /// Returns the cosine of this angle.
+    #[inline]
+    pub fn cos(self) -> FixedPoint {
+        (self + Self::QUARTER_TURN).sin()
+    }
+
+    /// Returns the sine and cosine of this angle.
+    #[inline]
+    pub fn sin_cos(self) -> (FixedPoint, FixedPoint) {
+        (self.sin(), self.cos())
+    }
+
+    /// Reads the sine of `steps` in the range `[0, 0x4000]` from [`SIN_TABLE`], interpolating linearly between entries.
+    #[inline]
+    fn quarter_sin(steps: u16) -> i32 {
+        let index = (steps >> 4) as usize;
+        let frac = (steps & 0xF) as i32;
+        if frac == 0 {
+            return SIN_TABLE[index];
+        }
+        let (start, end) = (SIN_TABLE[index], SIN_TABLE[index + 1]);
+        start + (((end - start) * frac) >> 4)
+    }
+}
+
+impl Add for FixedAngle {
+    type Output = FixedAngle;
+
+    /// Adds two angles, wrapping around a full turn.
+    #[inline]
+    fn add(self, rhs: FixedAngle) -> Self::Output {
+        FixedAngle(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl AddAssign for FixedAngle {
+    #[inline]
+    fn add_assign(&mut self, rhs: FixedAngle) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for FixedAngle {
+    type Output = FixedAngle;
+
+    /// Subtracts two angles, wrapping around a full turn.
+    #[inline]
+    fn sub(self, rhs: FixedAngle) -> Self::Output {
+        FixedAngle(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl SubAssign for FixedAngle {
+    #[inline]
+    fn sub_assign(&mut self, rhs: FixedAngle) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for FixedAngle {
+    type Output = FixedAngle;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        FixedAngle(self.0.wrapping_neg())
+    }
+}
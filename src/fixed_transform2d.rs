@@ -0,0 +1,284 @@
+use bevy::prelude::*;
+
+use crate::{
+    fixed::{FixedAngle, FixedPoint, FixedVec2},
+    transform2d::Transform2d,
+};
+
+/// Describes the position of an [`Entity`] in 2d space with fixed-point numbers.
+///
+/// Every operation on a [`FixedTransform2d`] uses integer arithmetic and a lookup table for the rotation,
+/// so a simulation that only uses [`FixedTransform2d`] gives bit-identical results on every machine.
+/// Use it for lockstep and rollback netcode, where `f32` trigonometry can differ between platforms.
+///
+/// This component acts as a proxy to the [`Transform2d`] component, and thus *requires* that a [`Transform2d`] is present to function.
+/// The [`Transform2d`] is only written for rendering, so don't read it back into the simulation.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct FixedTransform2d {
+    /// The translation along the `X` and `Y` axes.
+    pub translation: FixedVec2,
+    /// The rotation. Positive values rotate anti-clockwise.
+    pub rotation: FixedAngle,
+    /// The scale along the `X` and `Y` axes.
+    pub scale: FixedVec2,
+    /// The translation along the `Z` axis.
+    ///
+    /// This is only used for rendering, see [`Transform2d::z_translation`].
+    pub z_translation: f32,
+}
+
+impl Default for FixedTransform2d {
+    fn default() -> Self {
+        FixedTransform2d::IDENTITY
+    }
+}
+
+impl FixedTransform2d {
+    /// Creates a new identity [`FixedTransform2d`], with no translation, rotation, and a scale of 1 on all axes.
+    ///
+    /// Translation is `FixedVec2::ZERO`, rotation is `FixedAngle::ZERO`, scale is `FixedVec2::ONE` and `z_translation` is `0.`.
+    pub const IDENTITY: Self = FixedTransform2d {
+        translation: FixedVec2::ZERO,
+        rotation: FixedAngle::ZERO,
+        scale: FixedVec2::ONE,
+        z_translation: 0.,
+    };
+
+    /// Creates a new [`FixedTransform2d`] at the position `(x, y)`.
+    ///
+    /// Rotation will be `FixedAngle::ZERO`, scale will be `FixedVec2::ONE` and `z_translation` will be `0.`.
+    #[inline]
+    pub const fn from_xy(x: FixedPoint, y: FixedPoint) -> Self {
+        FixedTransform2d::from_translation(FixedVec2::new(x, y))
+    }
+
+    /// Creates a new [`FixedTransform2d`] with `translation`.
+    ///
+    /// Rotation will be `FixedAngle::ZERO`, scale will be `FixedVec2::ONE` and `z_translation` will be `0.`.
+    #[inline]
+    pub const fn from_translation(translation: FixedVec2) -> Self {
+        FixedTransform2d {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`FixedTransform2d`] with `rotation`.
+    ///
+    /// Translation will be `FixedVec2::ZERO`, scale will be `FixedVec2::ONE` and `z_translation` will be `0.`.
+    #[inline]
+    pub const fn from_rotation(rotation: FixedAngle) -> Self {
+        FixedTransform2d {
+            rotation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`FixedTransform2d`] with `scale`.
+    ///
+    /// Translation will be `FixedVec2::ZERO`, rotation will be `FixedAngle::ZERO` and `z_translation` will be `0.`
+    #[inline]
+    pub const fn from_scale(scale: FixedVec2) -> Self {
+        FixedTransform2d {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns this [`FixedTransform2d`] with a new translation.
+    #[must_use]
+    #[inline]
+    pub const fn with_translation(mut self, translation: FixedVec2) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns this [`FixedTransform2d`] with a new rotation.
+    #[must_use]
+    #[inline]
+    pub const fn with_rotation(mut self, rotation: FixedAngle) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns this [`FixedTransform2d`] with a new scale.
+    #[must_use]
+    #[inline]
+    pub const fn with_scale(mut self, scale: FixedVec2) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns this [`FixedTransform2d`] with a new Z translation.
+    #[must_use]
+    #[inline]
+    pub const fn with_z_translation(mut self, z_translation: f32) -> Self {
+        self.z_translation = z_translation;
+        self
+    }
+
+    /// Get the unit vector in the local `X` direction.
+    #[inline]
+    pub fn local_x(&self) -> FixedVec2 {
+        let (sin, cos) = self.rotation.sin_cos();
+        FixedVec2::new(cos, sin)
+    }
+
+    /// Get the unit vector in the local `Y` direction.
+    #[inline]
+    pub fn local_y(&self) -> FixedVec2 {
+        let (sin, cos) = self.rotation.sin_cos();
+        FixedVec2::new(-sin, cos)
+    }
+
+    /// Translates this [`FixedTransform2d`] around a `point` in space.
+    #[inline]
+    pub fn translate_around(&mut self, point: FixedVec2, angle: FixedAngle) {
+        self.translation = point + (self.translation - point).rotate(angle);
+    }
+
+    /// Rotates this [`FixedTransform2d`] by `angle`, wrapping around a full turn.
+    #[inline]
+    pub fn rotate(&mut self, angle: FixedAngle) {
+        self.rotation += angle;
+    }
+
+    /// Rotates this [`FixedTransform2d`] around a `point` in space.
+    #[inline]
+    pub fn rotate_around(&mut self, point: FixedVec2, angle: FixedAngle) {
+        self.translate_around(point, angle);
+        self.rotate(angle);
+    }
+
+    /// Transforms the given `point`, applying scale, rotation and translation.
+    /// `z_translation` is ignored.
+    #[inline]
+    pub fn transform_point(&self, point: FixedVec2) -> FixedVec2 {
+        (point * self.scale).rotate(self.rotation) + self.translation
+    }
+
+    /// Multiplies `self` with `transform` component by component, returning the
+    /// resulting [`FixedTransform2d`]
+    #[inline]
+    #[must_use]
+    pub fn mul_transform(&self, transform: FixedTransform2d) -> Self {
+        FixedTransform2d {
+            translation: self.transform_point(transform.translation),
+            rotation: self.rotation + transform.rotation,
+            scale: self.scale * transform.scale,
+            z_translation: self.z_translation + transform.z_translation,
+        }
+    }
+
+    /// Returns this transform as a [`Transform2d`], for rendering.
+    ///
+    /// The conversion is deterministic, but the [`Transform2d`] should not be fed back into the simulation.
+    #[inline]
+    pub fn to_transform_2d(&self) -> Transform2d {
+        Transform2d {
+            translation: self.translation.to_vec2(),
+            rotation: self.rotation.to_radians(),
+            scale: self.scale.to_vec2(),
+            z_translation: self.z_translation,
+        }
+    }
+}
+
+impl From<FixedTransform2d> for Transform2d {
+    #[inline]
+    fn from(transform: FixedTransform2d) -> Self {
+        transform.to_transform_2d()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sin_cos_exact() {
+        assert_eq!(
+            FixedAngle::ZERO.sin_cos(),
+            (FixedPoint::ZERO, FixedPoint::ONE)
+        );
+        assert_eq!(
+            FixedAngle::QUARTER_TURN.sin_cos(),
+            (FixedPoint::ONE, FixedPoint::ZERO)
+        );
+        assert_eq!(
+            FixedAngle::HALF_TURN.sin_cos(),
+            (FixedPoint::ZERO, -FixedPoint::ONE)
+        );
+        assert_eq!(
+            FixedAngle::THREE_QUARTER_TURN.sin_cos(),
+            (-FixedPoint::ONE, FixedPoint::ZERO)
+        );
+
+        // One twelfth of a turn, 30 degrees, rounded down to a whole step.
+        let (sin, cos) = FixedAngle::from_bits(5461).sin_cos();
+        assert_eq!(sin.to_bits(), 32_766);
+        assert_eq!(cos.to_bits(), 56_757);
+        assert_eq!(
+            FixedAngle::from_bits(5461 + (1 << 15)).sin().to_bits(),
+            -32_766
+        );
+    }
+
+    #[test]
+    fn rotate_around_exact() {
+        let mut transform = FixedTransform2d::from_translation(FixedVec2::from_ints(3, 1));
+        transform.rotate_around(FixedVec2::from_ints(1, 1), FixedAngle::QUARTER_TURN);
+
+        assert_eq!(transform.translation, FixedVec2::from_ints(1, 3));
+        assert_eq!(transform.rotation, FixedAngle::QUARTER_TURN);
+        assert_eq!(transform.local_x(), FixedVec2::Y);
+        assert_eq!(transform.local_y(), -FixedVec2::X);
+
+        // Rotation wraps around exactly.
+        transform.rotate(FixedAngle::THREE_QUARTER_TURN);
+        assert_eq!(transform.rotation, FixedAngle::ZERO);
+    }
+
+    #[test]
+    fn simulation_exact() {
+        let mut transform = FixedTransform2d::from_scale(FixedVec2::from_ints(2, 2));
+        let step = FixedAngle::from_bits(1000);
+        let velocity = FixedVec2::new(FixedPoint::from_bits(12_345), FixedPoint::ZERO);
+        for _ in 0..100 {
+            transform.rotate(step);
+            transform.translation += transform.local_x() * velocity.x;
+        }
+
+        assert_eq!(transform.rotation.to_bits(), 34_464);
+        assert_eq!(
+            (
+                transform.translation.x.to_bits(),
+                transform.translation.y.to_bits()
+            ),
+            (-33_140, 254_582)
+        );
+        let point = transform.transform_point(FixedVec2::from_ints(1, -1));
+        assert_eq!((point.x.to_bits(), point.y.to_bits()), (-183_702, 362_708));
+    }
+
+    #[test]
+    fn to_transform_2d() {
+        let transform =
+            FixedTransform2d::from_xy(FixedPoint::from_int(-3), FixedPoint::from_bits(1 << 15))
+                .with_rotation(FixedAngle::HALF_TURN)
+                .with_z_translation(2.);
+
+        assert_eq!(
+            transform.to_transform_2d(),
+            Transform2d::from_xyz(-3., 0.5, 2.).with_rotation(std::f32::consts::PI)
+        );
+        assert_eq!(FixedPoint::from_f32(-1.25), FixedPoint::from_bits(-81_920));
+        assert_eq!(
+            FixedAngle::from_radians(-std::f32::consts::FRAC_PI_2),
+            FixedAngle::THREE_QUARTER_TURN
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use bevy::{math::Affine2, prelude::*};
+
+use crate::{global_transform2d::GlobalTransform2d, transform2d::Transform2d};
+
+/// Mirrors an [`Entity`] along its local axes, without touching its [`Transform2d`].
+///
+/// Mirroring with a negative [`Transform2d::scale`] doesn't survive a round trip through [`Transform`],
+/// because a [`Transform`] with a negative scale can be the same as one with a positive scale and a half turn.
+/// When a physics engine writes back into the [`Transform`], the mirroring can turn into a rotation.
+///
+/// The flip is applied to the [`GlobalTransform2d`] and the [`Transform`] used for rendering,
+/// and removed again when the [`Transform`] is synchronised back into the [`Transform2d`],
+/// so the [`Transform2d`] keeps its rotation and the sign of its scale.
+#[derive(Component, Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Flip2d {
+    /// Mirrors along the local `X` axis, so the entity faces the other way horizontally.
+    pub x: bool,
+    /// Mirrors along the local `Y` axis, so the entity is upside down.
+    pub y: bool,
+}
+
+impl Flip2d {
+    /// No flip.
+    pub const NONE: Self = Flip2d { x: false, y: false };
+    /// Mirrors along the local `X` axis.
+    pub const X: Self = Flip2d { x: true, y: false };
+    /// Mirrors along the local `Y` axis.
+    pub const Y: Self = Flip2d { x: false, y: true };
+    /// Mirrors along both local axes.
+    pub const XY: Self = Flip2d { x: true, y: true };
+
+    /// Returns the scale that mirrors along the flipped axes, `-1.` for flipped axes and `1.` otherwise.
+    #[inline]
+    pub fn signs(&self) -> Vec2 {
+        Vec2::new(if self.x { -1. } else { 1. }, if self.y { -1. } else { 1. })
+    }
+
+    /// Returns `transform` mirrored along the flipped axes.
+    #[inline]
+    pub fn flip(&self, mut transform: Transform2d) -> Transform2d {
+        transform.scale *= self.signs();
+        transform
+    }
+
+    /// Returns `global_transform` mirrored along the flipped local axes.
+    #[inline]
+    pub fn flip_global(&self, global_transform: GlobalTransform2d) -> GlobalTransform2d {
+        GlobalTransform2d::from(global_transform.affine() * Affine2::from_scale(self.signs()))
+            .with_z_translation(global_transform.z_translation())
+    }
+
+    /// Returns the [`Transform2d`] that, after being flipped, has the same 2D transform as `transform`.
+    ///
+    /// The sign of the scale is taken from `current`, so neither the flip nor a negative scale turns into a rotation.
+    #[inline]
+    pub fn unflip_transform(&self, transform: &Transform, current: &Transform2d) -> Transform2d {
+        let signs = self.signs() * current.scale.signum();
+        let x_axis = (transform.rotation * Vec3::X * transform.scale.x).truncate() * signs.x;
+        let y_axis = (transform.rotation * Vec3::Y * transform.scale.y).truncate() * signs.y;
+        Transform2d {
+            translation: transform.translation.truncate(),
+            rotation: x_axis.y.atan2(x_axis.x),
+            scale: Vec2::new(x_axis.length(), y_axis.length()) * current.scale.signum(),
+            z_translation: transform.translation.z,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use crate::rot2::Rot2;
+
+    use super::*;
+
+    const FLIPS: [Flip2d; 4] = [Flip2d::NONE, Flip2d::X, Flip2d::Y, Flip2d::XY];
+    const ROTATIONS: [f32; 8] = [
+        0.,
+        FRAC_PI_4,
+        FRAC_PI_2,
+        3. * FRAC_PI_4,
+        PI,
+        -3. * FRAC_PI_4,
+        -FRAC_PI_2,
+        -FRAC_PI_4,
+    ];
+
+    fn assert_same_2d(a: Transform2d, b: Transform2d) {
+        assert!(
+            a.translation.abs_diff_eq(b.translation, 1e-5)
+                && Rot2::radians(a.rotation)
+                    .local_x()
+                    .abs_diff_eq(Rot2::radians(b.rotation).local_x(), 1e-5)
+                && a.scale.abs_diff_eq(b.scale, 1e-5)
+                && a.z_translation == b.z_translation,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn round_trip_through_transform() {
+        for flip in FLIPS {
+            for rotation in ROTATIONS {
+                for scale in [Vec2::new(2., 3.), Vec2::new(-2., 3.)] {
+                    let transform_2d = Transform2d::from_xyz(4., -5., 1.)
+                        .with_rotation(rotation)
+                        .with_scale(scale);
+                    let transform = Transform::from(flip.flip(transform_2d));
+
+                    assert_same_2d(
+                        flip.unflip_transform(&transform, &transform_2d),
+                        transform_2d,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn physics_writeback_keeps_flip() {
+        for flip in FLIPS {
+            for rotation in ROTATIONS {
+                let transform_2d = Transform2d::from_xy(1., 2.).with_scale(Vec2::new(2., 3.));
+                let mut transform = Transform::from(flip.flip(transform_2d));
+                // A physics engine only writes the translation and rotation back.
+                transform.rotation = Quat::from_rotation_z(rotation);
+
+                let synced = flip.unflip_transform(&transform, &transform_2d);
+                assert_same_2d(synced, transform_2d.with_rotation(rotation));
+            }
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use bevy::{math::DVec2, prelude::*};
+
+use crate::{
+    affine_transform2d::AffineTransform2d, dtransform2d::DTransform2d,
+    fixed_transform2d::FixedTransform2d, grid_transform2d::GridTransform2d,
+    interpolation::Transform2dInterpolation, transform2d::Transform2d,
+};
+
+/// The origin of the `f32` world that double precision transforms are rendered relative to.
+///
+/// The [`Transform2d`] of a root [`DTransform2d`], [`FixedTransform2d`] or [`GridTransform2d`] entity is its translation minus this origin.
+/// Keep the origin close to the camera, so rendering stays precise wherever the camera goes.
+///
+/// When an entity has a [`FloatingOriginFocus`] the origin is moved automatically,
+/// whenever the focus gets further than `rebase_threshold` away from it.
+#[derive(Resource, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct FloatingOrigin2d {
+    /// The position of the origin in double precision world space.
+    pub origin: DVec2,
+    /// How far the [`FloatingOriginFocus`] can get from the origin along the `X` or `Y` axis before the origin is moved to it.
+    pub rebase_threshold: f32,
+}
+
+impl Default for FloatingOrigin2d {
+    fn default() -> Self {
+        FloatingOrigin2d::new(DVec2::ZERO)
+    }
+}
+
+impl FloatingOrigin2d {
+    /// The default `rebase_threshold`.
+    pub const DEFAULT_REBASE_THRESHOLD: f32 = 10_000.;
+
+    /// Creates a new [`FloatingOrigin2d`] at `origin`.
+    #[inline]
+    pub const fn new(origin: DVec2) -> Self {
+        FloatingOrigin2d {
+            origin,
+            rebase_threshold: Self::DEFAULT_REBASE_THRESHOLD,
+        }
+    }
+
+    /// Returns this [`FloatingOrigin2d`] with a new `rebase_threshold`.
+    #[must_use]
+    #[inline]
+    pub const fn with_rebase_threshold(mut self, rebase_threshold: f32) -> Self {
+        self.rebase_threshold = rebase_threshold;
+        self
+    }
+}
+
+/// Marks the [`Entity`], usually the camera or the player, that the [`FloatingOrigin2d`] follows.
+///
+/// The focus should not have a [`Parent`]. If more than one entity has this component the origin is not moved.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct FloatingOriginFocus;
+
+/// Sent when the [`FloatingOrigin2d`] is moved.
+///
+/// Use this to adjust world positions cached outside of [`Transform2d`]s.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct OriginRebased2d {
+    /// The amount the origin moved by. This was subtracted from the translation of every root [`Transform2d`] and [`AffineTransform2d`].
+    pub offset: Vec2,
+    /// The new position of the origin.
+    pub origin: DVec2,
+}
+
+/// Moves the [`FloatingOrigin2d`] to the [`FloatingOriginFocus`] once the focus gets too far away,
+/// and shifts every root [`Transform2d`] and [`AffineTransform2d`] by the same amount.
+///
+/// The [`Transform2d`] of entities with a [`DTransform2d`], [`FixedTransform2d`] or [`GridTransform2d`] is not shifted here,
+/// as it is recomputed from the new origin by the system that syncs it, like [`sync_dtransform_2d_to_2d`](crate::systems::sync_dtransform_2d_to_2d).
+/// The fixed steps recorded in the [`Transform2dInterpolation`] of root entities are shifted too, so they don't slide to their new position.
+pub fn rebase_floating_origin(
+    mut floating_origin: ResMut<FloatingOrigin2d>,
+    mut queries: ParamSet<(
+        Query<&Transform2d, (With<FloatingOriginFocus>, Without<Parent>)>,
+        Query<
+            &mut Transform2d,
+            (
+                Without<Parent>,
+                Without<DTransform2d>,
+                Without<FixedTransform2d>,
+                Without<GridTransform2d>,
+            ),
+        >,
+        Query<&mut Transform2dInterpolation, Without<Parent>>,
+        Query<&mut AffineTransform2d, Without<Parent>>,
+    )>,
+    mut rebased: EventWriter<OriginRebased2d>,
+) {
+    let Ok(offset) = queries.p0().get_single().map(|focus| focus.translation) else {
+        return;
+    };
+    if offset.abs().max_element() <= floating_origin.rebase_threshold {
+        return;
+    }
+
+    for mut transform in &mut queries.p1() {
+        transform.translation -= offset;
+    }
+    for mut interpolation in &mut queries.p2() {
+        interpolation.previous.translation -= offset;
+        interpolation.current.translation -= offset;
+    }
+    for mut affine_transform in &mut queries.p3() {
+        affine_transform.affine.translation -= offset;
+    }
+    floating_origin.origin += offset.as_dvec2();
+    rebased.send(OriginRebased2d {
+        offset,
+        origin: floating_origin.origin,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        ecs::{event::Events, schedule::Schedule},
+        math::Affine2,
+    };
+
+    use super::*;
+    use crate::{
+        fixed::FixedVec2,
+        grid_transform2d::Grid2dConfig,
+        systems::{sync_fixed_transform_2d_to_2d, sync_grid_transform_2d_to_2d},
+    };
+
+    #[test]
+    fn rebase() {
+        let mut world = World::new();
+        world.init_resource::<Events<OriginRebased2d>>();
+        world.insert_resource(FloatingOrigin2d::default().with_rebase_threshold(100.));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(rebase_floating_origin);
+
+        let focus = world
+            .spawn((Transform2d::from_xy(50., 0.), FloatingOriginFocus))
+            .id();
+        let other = world.spawn(Transform2d::from_xy(200., 10.)).id();
+        let double = world
+            .spawn((Transform2d::default(), DTransform2d::from_xy(1., 2.)))
+            .id();
+        let affine = world
+            .spawn(AffineTransform2d::from_affine(Affine2::from_translation(
+                Vec2::new(200., 0.),
+            )))
+            .id();
+        let interpolated = world
+            .spawn((
+                Transform2d::from_xy(200., 0.),
+                Transform2dInterpolation {
+                    previous: Transform2d::from_xy(190., 0.),
+                    current: Transform2d::from_xy(200., 0.),
+                },
+            ))
+            .id();
+
+        // Within the threshold, nothing moves.
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<FloatingOrigin2d>().origin, DVec2::ZERO);
+
+        world.get_mut::<Transform2d>(focus).unwrap().translation.x = 150.;
+        schedule.run(&mut world);
+
+        assert_eq!(
+            world.resource::<FloatingOrigin2d>().origin,
+            DVec2::new(150., 0.)
+        );
+        assert_eq!(
+            world.get::<Transform2d>(focus).unwrap().translation,
+            Vec2::ZERO
+        );
+        assert_eq!(
+            world.get::<Transform2d>(other).unwrap().translation,
+            Vec2::new(50., 10.)
+        );
+        assert_eq!(
+            world.get::<Transform2d>(double).unwrap().translation,
+            Vec2::ZERO
+        );
+        assert_eq!(
+            *world.get::<Transform2dInterpolation>(interpolated).unwrap(),
+            Transform2dInterpolation {
+                previous: Transform2d::from_xy(40., 0.),
+                current: Transform2d::from_xy(50., 0.),
+            }
+        );
+
+        assert_eq!(
+            world
+                .get::<AffineTransform2d>(affine)
+                .unwrap()
+                .affine
+                .translation,
+            Vec2::new(50., 0.)
+        );
+
+        let events = world.resource::<Events<OriginRebased2d>>();
+        let mut reader = events.get_reader();
+        let event = reader.read(events).next().unwrap();
+        assert_eq!(event.offset, Vec2::new(150., 0.));
+    }
+
+    #[test]
+    fn rebase_synced_sources() {
+        let mut world = World::new();
+        world.init_resource::<Events<OriginRebased2d>>();
+        world.init_resource::<Grid2dConfig>();
+        world.insert_resource(FloatingOrigin2d::default().with_rebase_threshold(100.));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                rebase_floating_origin,
+                sync_fixed_transform_2d_to_2d,
+                sync_grid_transform_2d_to_2d,
+            )
+                .chain(),
+        );
+
+        world.spawn((Transform2d::from_xy(150., 0.), FloatingOriginFocus));
+        let fixed = world
+            .spawn((
+                Transform2d::default(),
+                FixedTransform2d::from_translation(FixedVec2::from_ints(200, 10)),
+            ))
+            .id();
+        let grid = world
+            .spawn((Transform2d::default(), GridTransform2d::from_xy(200, 10)))
+            .id();
+        schedule.run(&mut world);
+
+        // Both are recomputed relative to the new origin, and their sources are left alone.
+        for entity in [fixed, grid] {
+            assert_eq!(
+                world.get::<Transform2d>(entity).unwrap().translation,
+                Vec2::new(50., 10.)
+            );
+        }
+        assert_eq!(
+            world.get::<FixedTransform2d>(fixed).unwrap().translation,
+            FixedVec2::from_ints(200, 10)
+        );
+    }
+}
@@ -0,0 +1,379 @@
+use std::ops::Mul;
+
+use bevy::{
+    math::{Affine2, Affine3A, Vec3A},
+    prelude::*,
+};
+
+use crate::{affine_transform2d::AffineTransform2d, transform2d::Transform2d};
+
+/// Describes the position of an [`Entity`] in 2d space, relative to the reference frame.
+///
+/// * To place or move an entity, you should set its [`Transform2d`].
+/// * [`GlobalTransform2d`] is managed by the [`Transform2dPlugin`](crate::Transform2dPlugin), you cannot mutate it, use
+///   [`Transform2d`] instead.
+/// * To get the global 2d transform of an entity, you should get its [`GlobalTransform2d`].
+///
+/// [`GlobalTransform2d`] is updated from [`Transform2d`] by the systems in the [`Transform2dPropagate`](crate::Transform2dPropagate) system set.
+/// Propagation follows the hierarchy for as long as every ancestor has both a [`Transform2d`] and a [`GlobalTransform2d`].
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct GlobalTransform2d {
+    affine: Affine2,
+    z_translation: f32,
+}
+
+impl Default for GlobalTransform2d {
+    fn default() -> Self {
+        GlobalTransform2d::IDENTITY
+    }
+}
+
+impl GlobalTransform2d {
+    /// An identity [`GlobalTransform2d`] that maps all points in space to themselves.
+    pub const IDENTITY: Self = GlobalTransform2d {
+        affine: Affine2::IDENTITY,
+        z_translation: 0.,
+    };
+
+    /// Creates a new [`GlobalTransform2d`] at the position `(x, y)`.
+    #[inline]
+    pub fn from_xy(x: f32, y: f32) -> Self {
+        Self::from_translation(Vec2::new(x, y))
+    }
+
+    /// Creates a new [`GlobalTransform2d`] with `translation`.
+    #[inline]
+    pub fn from_translation(translation: Vec2) -> Self {
+        GlobalTransform2d {
+            affine: Affine2::from_translation(translation),
+            z_translation: 0.,
+        }
+    }
+
+    /// Creates a new [`GlobalTransform2d`] with `rotation`.
+    #[inline]
+    pub fn from_rotation(rotation: f32) -> Self {
+        GlobalTransform2d {
+            affine: Affine2::from_angle(rotation),
+            z_translation: 0.,
+        }
+    }
+
+    /// Creates a new [`GlobalTransform2d`] with `scale`.
+    #[inline]
+    pub fn from_scale(scale: Vec2) -> Self {
+        GlobalTransform2d {
+            affine: Affine2::from_scale(scale),
+            z_translation: 0.,
+        }
+    }
+
+    /// Returns this [`GlobalTransform2d`] with a new Z translation.
+    #[must_use]
+    #[inline]
+    pub fn with_z_translation(mut self, z_translation: f32) -> Self {
+        self.z_translation = z_translation;
+        self
+    }
+
+    /// Returns the 2d affine transformation as an [`Affine2`].
+    #[inline]
+    pub fn affine(&self) -> Affine2 {
+        self.affine
+    }
+
+    /// Returns the 2d affine transformation matrix as a [`Mat3`].
+    #[inline]
+    pub fn compute_matrix(&self) -> Mat3 {
+        Mat3::from(self.affine)
+    }
+
+    /// Returns the transformation as a [`Transform2d`].
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    #[inline]
+    pub fn compute_transform(&self) -> Transform2d {
+        let (scale, rotation, translation) = self.to_scale_angle_translation();
+        Transform2d {
+            translation,
+            rotation,
+            scale,
+            z_translation: self.z_translation,
+        }
+    }
+
+    /// Returns the [`Transform2d`] `self` would have if it was a child of an entity
+    /// with the `parent` [`GlobalTransform2d`].
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    #[inline]
+    pub fn reparented_to(&self, parent: &GlobalTransform2d) -> Transform2d {
+        let relative_affine = parent.affine.inverse() * self.affine;
+        let (scale, rotation, translation) = relative_affine.to_scale_angle_translation();
+        Transform2d {
+            translation,
+            rotation,
+            scale,
+            z_translation: self.z_translation - parent.z_translation,
+        }
+    }
+
+    /// Extracts `scale`, `rotation` and `translation` from `self`.
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    #[inline]
+    pub fn to_scale_angle_translation(&self) -> (Vec2, f32, Vec2) {
+        self.affine.to_scale_angle_translation()
+    }
+
+    /// Get the translation along the `X` and `Y` axes.
+    #[inline]
+    pub fn translation(&self) -> Vec2 {
+        self.affine.translation
+    }
+
+    /// Get the translation along the `X`, `Y` and `Z` axes.
+    #[inline]
+    pub fn translation_3d(&self) -> Vec3 {
+        self.affine.translation.extend(self.z_translation)
+    }
+
+    /// Get the rotation in radians. Positive values rotate anti-clockwise.
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    #[inline]
+    pub fn rotation(&self) -> f32 {
+        self.to_scale_angle_translation().1
+    }
+
+    /// Get the scale along the `X` and `Y` axes.
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid.
+    #[inline]
+    pub fn scale(&self) -> Vec2 {
+        self.to_scale_angle_translation().0
+    }
+
+    /// Get the translation along the `Z` axis.
+    ///
+    /// Unlike [`Transform2d::z_translation`] this includes the `z_translation` of all ancestors.
+    #[inline]
+    pub fn z_translation(&self) -> f32 {
+        self.z_translation
+    }
+
+    /// Get the unit vector in the local `X` direction.
+    #[inline]
+    pub fn local_x(&self) -> Vec2 {
+        self.affine.matrix2.x_axis.normalize()
+    }
+
+    /// Equivalent to [`-local_x()`][Self::local_x()]
+    #[inline]
+    pub fn left(&self) -> Vec2 {
+        -self.local_x()
+    }
+
+    /// Equivalent to [`local_x()`][Self::local_x()]
+    #[inline]
+    pub fn right(&self) -> Vec2 {
+        self.local_x()
+    }
+
+    /// Get the unit vector in the local `Y` direction.
+    #[inline]
+    pub fn local_y(&self) -> Vec2 {
+        self.affine.matrix2.y_axis.normalize()
+    }
+
+    /// Equivalent to [`local_y()`][Self::local_y]
+    #[inline]
+    pub fn up(&self) -> Vec2 {
+        self.local_y()
+    }
+
+    /// Equivalent to [`-local_y()`][Self::local_y]
+    #[inline]
+    pub fn down(&self) -> Vec2 {
+        -self.local_y()
+    }
+
+    /// Transforms the given `point`, applying shear, scale, rotation and translation.
+    ///
+    /// This moves a `point` in the local space of this [`GlobalTransform2d`] into global space.
+    #[inline]
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.affine.transform_point2(point)
+    }
+
+    /// Transforms the given `point` by the inverse of this [`GlobalTransform2d`].
+    ///
+    /// This moves a `point` in global space into the local space of this [`GlobalTransform2d`],
+    /// eg. to find where the mouse cursor is relative to an entity.
+    #[inline]
+    pub fn inverse_transform_point(&self, point: Vec2) -> Vec2 {
+        self.affine.inverse().transform_point2(point)
+    }
+
+    /// Multiplies `self` with `transform` component by component, returning the
+    /// resulting [`GlobalTransform2d`]
+    #[inline]
+    #[must_use]
+    pub fn mul_transform(&self, transform: Transform2d) -> Self {
+        GlobalTransform2d {
+            affine: self.affine * transform.compute_affine(),
+            z_translation: self.z_translation + transform.z_translation,
+        }
+    }
+
+    /// Multiplies `self` with `transform`, returning the resulting [`GlobalTransform2d`]
+    #[inline]
+    #[must_use]
+    pub fn mul_affine_transform(&self, transform: AffineTransform2d) -> Self {
+        GlobalTransform2d {
+            affine: self.affine * transform.affine,
+            z_translation: self.z_translation + transform.z_translation,
+        }
+    }
+}
+
+impl From<Transform2d> for GlobalTransform2d {
+    #[inline]
+    fn from(transform: Transform2d) -> Self {
+        GlobalTransform2d {
+            affine: transform.compute_affine(),
+            z_translation: transform.z_translation,
+        }
+    }
+}
+
+impl From<AffineTransform2d> for GlobalTransform2d {
+    #[inline]
+    fn from(transform: AffineTransform2d) -> Self {
+        GlobalTransform2d {
+            affine: transform.affine,
+            z_translation: transform.z_translation,
+        }
+    }
+}
+
+impl From<Affine2> for GlobalTransform2d {
+    #[inline]
+    fn from(affine: Affine2) -> Self {
+        GlobalTransform2d {
+            affine,
+            z_translation: 0.,
+        }
+    }
+}
+
+impl From<GlobalTransform2d> for GlobalTransform {
+    #[inline]
+    fn from(global_transform: GlobalTransform2d) -> Self {
+        let Affine2 {
+            matrix2,
+            translation,
+        } = global_transform.affine;
+        GlobalTransform::from(Affine3A::from_cols(
+            Vec3A::from(matrix2.x_axis.extend(0.)),
+            Vec3A::from(matrix2.y_axis.extend(0.)),
+            Vec3A::Z,
+            Vec3A::from(translation.extend(global_transform.z_translation)),
+        ))
+    }
+}
+
+impl Mul<GlobalTransform2d> for GlobalTransform2d {
+    type Output = GlobalTransform2d;
+
+    #[inline]
+    fn mul(self, global_transform: GlobalTransform2d) -> Self::Output {
+        GlobalTransform2d {
+            affine: self.affine * global_transform.affine,
+            z_translation: self.z_translation + global_transform.z_translation,
+        }
+    }
+}
+
+impl Mul<Transform2d> for GlobalTransform2d {
+    type Output = GlobalTransform2d;
+
+    #[inline]
+    fn mul(self, transform: Transform2d) -> Self::Output {
+        self.mul_transform(transform)
+    }
+}
+
+impl Mul<Vec2> for GlobalTransform2d {
+    type Output = Vec2;
+
+    #[inline]
+    fn mul(self, value: Vec2) -> Self::Output {
+        self.transform_point(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    #[test]
+    fn mul_transform_matches_transform2d() {
+        let parent = Transform2d::from_xy(10., 0.)
+            .with_rotation(FRAC_PI_2)
+            .with_scale(Vec2::new(2., 3.))
+            .with_z_translation(1.);
+        let child = Transform2d::from_xy(1., 2.).with_z_translation(0.5);
+
+        let global = GlobalTransform2d::from(parent).mul_transform(child);
+        let expected = parent.mul_transform(child);
+
+        assert!(global.translation().abs_diff_eq(expected.translation, 1e-5));
+        assert_eq!(global.z_translation(), expected.z_translation);
+    }
+
+    #[test]
+    fn into_global_transform() {
+        let transform = Transform2d::from_xy(3., -2.)
+            .with_rotation(0.7)
+            .with_scale(Vec2::new(2., -1.))
+            .with_z_translation(4.);
+
+        let global_transform = GlobalTransform::from(GlobalTransform2d::from(transform));
+        let expected = GlobalTransform::from(Transform::from(transform));
+
+        assert!(global_transform
+            .affine()
+            .abs_diff_eq(expected.affine(), 1e-5));
+    }
+
+    #[test]
+    fn reparent_roundtrip() {
+        let parent = GlobalTransform2d::from(
+            Transform2d::from_xy(-4., 7.)
+                .with_rotation(1.2)
+                .with_z_translation(2.),
+        );
+        let child = Transform2d::from_xy(3., 1.)
+            .with_rotation(-0.3)
+            .with_scale(Vec2::splat(2.))
+            .with_z_translation(1.);
+
+        let reparented = (parent * child).reparented_to(&parent);
+
+        assert!(reparented.translation.abs_diff_eq(child.translation, 1e-5));
+        assert!((reparented.rotation - child.rotation).abs() < 1e-5);
+        assert!(reparented.scale.abs_diff_eq(child.scale, 1e-5));
+        assert_eq!(reparented.z_translation, child.z_translation);
+    }
+}
@@ -0,0 +1,277 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::prelude::*;
+
+use crate::transform2d::Transform2d;
+
+/// Describes the position of an [`Entity`] on an integer grid, for tile-based games.
+///
+/// This component acts as a proxy to the [`Transform2d`] component, and thus *requires* that a [`Transform2d`] is present to function.
+/// The translation, rotation and `z_translation` of the [`Transform2d`] are written from the cell, facing and layer
+/// using the [`Grid2dConfig`] resource, while its scale is left untouched.
+#[derive(Component, Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct GridTransform2d {
+    /// The cell of the grid this entity is in.
+    pub cell: IVec2,
+    /// The direction this entity faces, relative to the grid.
+    pub facing: Facing8,
+    /// The layer of this entity, used as the `z_translation` of the [`Transform2d`] unless the entity has a [`ZOrder`](crate::z_order::ZOrder).
+    pub layer: i32,
+}
+
+impl GridTransform2d {
+    /// Creates a new [`GridTransform2d`] in the cell `(x, y)`.
+    ///
+    /// Facing will be [`Facing8::Right`] and layer will be `0`.
+    #[inline]
+    pub const fn from_xy(x: i32, y: i32) -> Self {
+        GridTransform2d::from_cell(IVec2::new(x, y))
+    }
+
+    /// Creates a new [`GridTransform2d`] in `cell`.
+    ///
+    /// Facing will be [`Facing8::Right`] and layer will be `0`.
+    #[inline]
+    pub const fn from_cell(cell: IVec2) -> Self {
+        GridTransform2d {
+            cell,
+            facing: Facing8::Right,
+            layer: 0,
+        }
+    }
+
+    /// Returns this [`GridTransform2d`] with a new facing.
+    #[must_use]
+    #[inline]
+    pub fn with_facing(mut self, facing: impl Into<Facing8>) -> Self {
+        self.facing = facing.into();
+        self
+    }
+
+    /// Returns this [`GridTransform2d`] with a new layer.
+    #[must_use]
+    #[inline]
+    pub const fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Returns the cell next to this one in the direction this entity faces.
+    #[inline]
+    pub fn cell_ahead(&self) -> IVec2 {
+        self.cell + self.facing.as_ivec2()
+    }
+
+    /// Writes the translation, rotation and `z_translation` of `transform` from this grid position.
+    ///
+    /// The rotation points towards the [`GridTransform2d::cell_ahead`], see [`Grid2dConfig::facing_to_radians`].
+    #[inline]
+    pub fn write_transform_2d(&self, config: &Grid2dConfig, transform: &mut Transform2d) {
+        transform.translation = config.cell_to_world(self.cell);
+        transform.rotation = config.facing_to_radians(self.facing);
+        transform.z_translation = self.layer as f32;
+    }
+}
+
+/// The configuration of the grid used by [`GridTransform2d`].
+#[derive(Resource, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Grid2dConfig {
+    /// The size of a cell along the `X` and `Y` axes of the grid.
+    ///
+    /// Use a negative `y` for grids where rows go down, like most tile map formats.
+    pub cell_size: Vec2,
+    /// The world position of the center of the cell `(0, 0)`.
+    pub origin: Vec2,
+    /// The rotation of the grid in radians. Positive values rotate anti-clockwise.
+    pub orientation: f32,
+}
+
+impl Default for Grid2dConfig {
+    fn default() -> Self {
+        Grid2dConfig {
+            cell_size: Vec2::ONE,
+            origin: Vec2::ZERO,
+            orientation: 0.,
+        }
+    }
+}
+
+impl Grid2dConfig {
+    /// Returns the world position of the center of `cell`.
+    #[inline]
+    pub fn cell_to_world(&self, cell: IVec2) -> Vec2 {
+        self.origin + Vec2::from_angle(self.orientation).rotate(cell.as_vec2() * self.cell_size)
+    }
+
+    /// Returns the angle in radians, relative to the world `X` axis, from a cell towards its neighbour in the `facing` direction.
+    ///
+    /// This is [`Facing8::as_radians`] plus the orientation for a grid with square cells and a positive cell size.
+    /// Otherwise the angle is adjusted, so eg. [`Facing8::Up`] points down the world `Y` axis if the `y` of the cell size is negative,
+    /// because that's where the next row is.
+    #[inline]
+    pub fn facing_to_radians(&self, facing: impl Into<Facing8>) -> f32 {
+        let direction = facing.into().as_ivec2().as_vec2() * self.cell_size;
+        self.orientation + direction.y.atan2(direction.x)
+    }
+
+    /// Returns the cell that contains the world position `point`.
+    #[inline]
+    pub fn world_to_cell(&self, point: Vec2) -> IVec2 {
+        let local = Vec2::from_angle(-self.orientation).rotate(point - self.origin);
+        (local / self.cell_size).round().as_ivec2()
+    }
+}
+
+/// One of the four directions along the axes of a grid.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum Facing4 {
+    /// Towards positive `X`.
+    #[default]
+    Right,
+    /// Towards positive `Y`.
+    Up,
+    /// Towards negative `X`.
+    Left,
+    /// Towards negative `Y`.
+    Down,
+}
+
+impl Facing4 {
+    /// Returns the offset to the neighbouring cell in this direction.
+    #[inline]
+    pub fn as_ivec2(self) -> IVec2 {
+        Facing8::from(self).as_ivec2()
+    }
+
+    /// Returns the angle of this direction in radians, relative to the `X` axis of the grid.
+    #[inline]
+    pub fn as_radians(self) -> f32 {
+        Facing8::from(self).as_radians()
+    }
+}
+
+/// One of the eight directions along the axes and diagonals of a grid.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum Facing8 {
+    /// Towards positive `X`.
+    #[default]
+    Right,
+    /// Towards positive `X` and positive `Y`.
+    UpRight,
+    /// Towards positive `Y`.
+    Up,
+    /// Towards negative `X` and positive `Y`.
+    UpLeft,
+    /// Towards negative `X`.
+    Left,
+    /// Towards negative `X` and negative `Y`.
+    DownLeft,
+    /// Towards negative `Y`.
+    Down,
+    /// Towards positive `X` and negative `Y`.
+    DownRight,
+}
+
+impl Facing8 {
+    /// Returns the offset to the neighbouring cell in this direction.
+    #[inline]
+    pub fn as_ivec2(self) -> IVec2 {
+        match self {
+            Facing8::Right => IVec2::new(1, 0),
+            Facing8::UpRight => IVec2::new(1, 1),
+            Facing8::Up => IVec2::new(0, 1),
+            Facing8::UpLeft => IVec2::new(-1, 1),
+            Facing8::Left => IVec2::new(-1, 0),
+            Facing8::DownLeft => IVec2::new(-1, -1),
+            Facing8::Down => IVec2::new(0, -1),
+            Facing8::DownRight => IVec2::new(1, -1),
+        }
+    }
+
+    /// Returns the angle of this direction in radians, relative to the `X` axis of the grid.
+    ///
+    /// Use [`Grid2dConfig::facing_to_radians`] for the angle in the world.
+    #[inline]
+    pub fn as_radians(self) -> f32 {
+        self as u8 as f32 * FRAC_PI_4
+    }
+}
+
+impl From<Facing4> for Facing8 {
+    #[inline]
+    fn from(facing: Facing4) -> Self {
+        match facing {
+            Facing4::Right => Facing8::Right,
+            Facing4::Up => Facing8::Up,
+            Facing4::Left => Facing8::Left,
+            Facing4::Down => Facing8::Down,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    #[test]
+    fn write_transform_2d() {
+        let config = Grid2dConfig {
+            cell_size: Vec2::new(16., -16.),
+            origin: Vec2::new(8., -8.),
+            orientation: 0.,
+        };
+        let grid = GridTransform2d::from_xy(2, 3)
+            .with_facing(Facing4::Up)
+            .with_layer(4);
+        let mut transform = Transform2d::from_scale(2.);
+        grid.write_transform_2d(&config, &mut transform);
+
+        assert_eq!(transform.translation, Vec2::new(40., -56.));
+        // Rows go down, so the next row is below.
+        assert_eq!(transform.rotation, -FRAC_PI_2);
+        assert_eq!(transform.z_translation, 4.);
+        assert_eq!(transform.scale, Vec2::splat(2.));
+        assert_eq!(config.world_to_cell(Vec2::new(45., -50.)), grid.cell);
+        assert_eq!(grid.cell_ahead(), IVec2::new(2, 4));
+    }
+
+    #[test]
+    fn facing_points_at_cell_ahead() {
+        for cell_size in [
+            Vec2::new(16., 16.),
+            Vec2::new(16., -16.),
+            Vec2::new(-16., 8.),
+        ] {
+            let config = Grid2dConfig {
+                cell_size,
+                origin: Vec2::new(8., -8.),
+                orientation: 0.5,
+            };
+            for facing in [Facing8::Up, Facing8::DownLeft, Facing8::Right] {
+                let grid = GridTransform2d::from_xy(2, 3).with_facing(facing);
+                let mut transform = Transform2d::default();
+                grid.write_transform_2d(&config, &mut transform);
+
+                let ahead = config.cell_to_world(grid.cell_ahead()) - transform.translation;
+                assert!(
+                    Vec2::from_angle(transform.rotation).abs_diff_eq(ahead.normalize(), 1e-5),
+                    "{facing:?} with a cell size of {cell_size}"
+                );
+            }
+        }
+    }
+}
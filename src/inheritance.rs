@@ -0,0 +1,127 @@
+use bevy::{math::Affine2, prelude::*};
+
+use crate::global_transform2d::GlobalTransform2d;
+
+/// Selects which parts of the [`GlobalTransform2d`] of its [`Parent`] an [`Entity`] inherits.
+///
+/// Use this for children like health bars, name tags and shadows that follow a rotating or scaled sprite,
+/// but must stay upright and unscaled themselves.
+/// Entities without this component inherit everything.
+///
+/// When a part isn't inherited the parent is treated as if it had no translation, rotation or scale.
+/// The z of the parent is always inherited, use [`ZMode::Absolute`](crate::z_order::ZMode::Absolute) to ignore it.
+/// For example a child that doesn't inherit rotation keeps its offset from the parent along the world axes.
+///
+/// A [`Transform`] can't undo the shear that a rotated parent with a non-uniform scale gives its children,
+/// so children with a [`Transform`] should only skip rotation or scale of parents with a uniform scale.
+#[derive(Component, Debug, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Transform2dInheritance {
+    /// Whether the translation along the `X` and `Y` axes of the parent is inherited.
+    pub translation: bool,
+    /// Whether the rotation of the parent is inherited.
+    pub rotation: bool,
+    /// Whether the scale of the parent is inherited.
+    pub scale: bool,
+}
+
+impl Default for Transform2dInheritance {
+    fn default() -> Self {
+        Transform2dInheritance::ALL
+    }
+}
+
+impl Transform2dInheritance {
+    /// Inherits everything from the parent.
+    pub const ALL: Self = Transform2dInheritance {
+        translation: true,
+        rotation: true,
+        scale: true,
+    };
+
+    /// Only inherits the translation of the parent, so the entity stays upright and unscaled.
+    pub const TRANSLATION_ONLY: Self = Transform2dInheritance {
+        rotation: false,
+        scale: false,
+        ..Self::ALL
+    };
+
+    /// Returns this [`Transform2dInheritance`] with the rotation inherited or not.
+    #[must_use]
+    #[inline]
+    pub const fn with_rotation(mut self, rotation: bool) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns this [`Transform2dInheritance`] with the scale inherited or not.
+    #[must_use]
+    #[inline]
+    pub const fn with_scale(mut self, scale: bool) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns this [`Transform2dInheritance`] with the translation inherited or not.
+    #[must_use]
+    #[inline]
+    pub const fn with_translation(mut self, translation: bool) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns the parts of `parent` that are inherited.
+    ///
+    /// If the rotation or scale isn't inherited, the shear of `parent` is lost.
+    #[inline]
+    pub fn inherit(&self, parent: &GlobalTransform2d) -> GlobalTransform2d {
+        if self.translation && self.rotation && self.scale {
+            return *parent;
+        }
+
+        let (scale, rotation, translation) = parent.to_scale_angle_translation();
+        GlobalTransform2d::from(Affine2::from_scale_angle_translation(
+            if self.scale { scale } else { Vec2::ONE },
+            if self.rotation { rotation } else { 0. },
+            if self.translation {
+                translation
+            } else {
+                Vec2::ZERO
+            },
+        ))
+        .with_z_translation(parent.z_translation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transform2d::Transform2d;
+
+    use super::*;
+
+    #[test]
+    fn inherit() {
+        let parent = GlobalTransform2d::from(
+            Transform2d::from_xyz(5., 3., 2.)
+                .with_rotation(1.)
+                .with_scale(Vec2::splat(2.)),
+        );
+
+        assert_eq!(Transform2dInheritance::ALL.inherit(&parent), parent);
+
+        let inherited = Transform2dInheritance::TRANSLATION_ONLY.inherit(&parent);
+        assert_eq!(
+            inherited,
+            GlobalTransform2d::from_xy(5., 3.).with_z_translation(2.)
+        );
+
+        let inherited = Transform2dInheritance::ALL
+            .with_scale(false)
+            .inherit(&parent);
+        assert!((inherited.rotation() - 1.).abs() < 1e-6);
+        assert!(inherited.scale().abs_diff_eq(Vec2::ONE, 1e-6));
+        assert_eq!(inherited.z_translation(), 2.);
+    }
+}
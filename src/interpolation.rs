@@ -0,0 +1,214 @@
+use bevy::{ecs::system::SystemChangeTick, prelude::*, transform::TransformSystem};
+
+use crate::{
+    global_transform2d::GlobalTransform2d,
+    systems::{
+        self, propagated_parent, AncestorQuery, PropagationOverrides3d, Transform2dSyncTick,
+        Transform2dTo3dOptions, Transform2dTo3dParams,
+    },
+    transform2d::Transform2d,
+    SyncTransform2dTo3d, Transform2dPropagate,
+};
+
+/// The [`Plugin`] for visually interpolating [`Transform2d`]s that are updated in [`FixedUpdate`].
+///
+/// Gameplay that runs in [`FixedUpdate`] only moves entities once per fixed step,
+/// which makes them stutter when the frame rate doesn't match the fixed timestep.
+/// For entities with a [`Transform2dInterpolation`] component this plugin writes a [`Transform`]
+/// that's interpolated between the two most recent fixed steps, based on [`Time::<Fixed>::overstep_percentage`].
+///
+/// The [`Transform2d`] and [`GlobalTransform2d`] keep the value of the latest fixed step,
+/// only the [`Transform`] used for rendering is interpolated.
+/// Pure 2D entities have no [`Transform`], so they aren't interpolated.
+/// The z that a [`ZMode`](crate::z_order::ZMode) or [`YSort`](crate::y_sort::YSort) gives an entity isn't interpolated.
+///
+/// This plugin requires the [`Transform2dPlugin`](crate::Transform2dPlugin).
+#[derive(Default)]
+pub struct Transform2dInterpolationPlugin;
+
+/// The [`SystemSet`] in [`FixedUpdate`] that records the [`Transform2d`] of every fixed step.
+///
+/// Systems in [`FixedUpdate`] that modify a [`Transform2d`] should run before this set.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct RecordTransform2d;
+
+/// Enables visual interpolation of the [`Transform2d`] of an [`Entity`] across fixed steps.
+///
+/// Requires the [`Transform2dInterpolationPlugin`], and a [`Transform`] to write the interpolated value into.
+/// A warning is logged in debug builds if this is added to an entity without a [`Transform`].
+#[derive(Component, Debug, Default, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Transform2dInterpolation {
+    /// The [`Transform2d`] at the end of the second to last fixed step.
+    pub previous: Transform2d,
+    /// The [`Transform2d`] at the end of the last fixed step.
+    pub current: Transform2d,
+}
+
+/// Skips interpolation for one frame, so the [`Entity`] jumps straight to its current [`Transform2d`].
+///
+/// Add this when moving an entity with a [`Transform2dInterpolation`] a large distance at once,
+/// so it doesn't visibly slide to its new position. It's removed automatically.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Teleport2d;
+
+impl Plugin for Transform2dInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Transform2dInterpolation>()
+            .register_type::<Teleport2d>()
+            .add_systems(FixedUpdate, record_transform_2d.in_set(RecordTransform2d))
+            .add_systems(
+                PostUpdate,
+                interpolate_transform_2d
+                    .in_set(Transform2dPropagate)
+                    .after(SyncTransform2dTo3d)
+                    .after(systems::sync_propagation_overrides_to_3d)
+                    .before(TransformSystem::TransformPropagate),
+            );
+
+        #[cfg(debug_assertions)]
+        app.add_systems(PostUpdate, warn_interpolation_without_transform);
+    }
+}
+
+/// Stores the [`Transform2d`] of the latest fixed step in [`Transform2dInterpolation`].
+pub fn record_transform_2d(mut query: Query<(&Transform2d, &mut Transform2dInterpolation)>) {
+    for (&transform, mut interpolation) in &mut query {
+        interpolation.previous = if interpolation.is_added() {
+            transform
+        } else {
+            interpolation.current
+        };
+        interpolation.current = transform;
+    }
+}
+
+/// Logs a warning for entities with a [`Transform2dInterpolation`] but no [`Transform`],
+/// such as pure 2D entities, which [`interpolate_transform_2d`] skips.
+pub fn warn_interpolation_without_transform(
+    query: Query<Entity, (Added<Transform2dInterpolation>, Without<Transform>)>,
+) {
+    for entity in &query {
+        warn!("{entity:?} has a Transform2dInterpolation but no Transform, so it won't be interpolated");
+    }
+}
+
+/// Writes the [`Transform`] of entities with a [`Transform2dInterpolation`],
+/// interpolated between the last two fixed steps.
+pub fn interpolate_transform_2d(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &Transform2d,
+        &mut Transform2dInterpolation,
+        &mut Transform,
+        Option<&mut Transform2dSyncTick>,
+        Option<&GlobalTransform2d>,
+        Option<&Parent>,
+        Transform2dTo3dOptions,
+        PropagationOverrides3d,
+        Has<Teleport2d>,
+    )>,
+    ancestor_query: AncestorQuery,
+    params: Transform2dTo3dParams,
+    time: Res<Time<Fixed>>,
+    system_ticks: SystemChangeTick,
+) {
+    let overstep = time.overstep_percentage();
+    let transform_2d_to_3d = params.get();
+    for (
+        entity,
+        &transform_2d,
+        mut interpolation,
+        mut transform_3d,
+        sync_tick,
+        global_transform,
+        parent,
+        options,
+        overrides,
+        teleport,
+    ) in &mut query
+    {
+        let transform = if teleport || interpolation.is_added() {
+            commands.entity(entity).remove::<Teleport2d>();
+            interpolation.previous = transform_2d;
+            interpolation.current = transform_2d;
+            transform_2d
+        } else {
+            interpolation.previous.lerp(interpolation.current, overstep)
+        };
+
+        // Entities with overrides would otherwise be overwritten by `sync_propagation_overrides_to_3d`.
+        let propagated = if overrides.is_empty() {
+            None
+        } else {
+            global_transform.zip(propagated_parent(parent, &ancestor_query))
+        };
+        let new_transform_3d = match propagated {
+            Some((global_transform, parent)) => transform_2d_to_3d.overridden_transform_3d(
+                transform,
+                global_transform.z_translation(),
+                &parent,
+                &options,
+                &overrides,
+            ),
+            None => transform_2d_to_3d.transform_3d(transform, &options),
+        };
+        // Our own write shouldn't be synchronised back into the `Transform2d`.
+        if transform_3d.set_if_neq(new_transform_3d) {
+            if let Some(mut sync_tick) = sync_tick {
+                sync_tick.set(system_ticks.this_run());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bundle::Transform2dBundle,
+        y_sort::{YSort, YSortConfig},
+        Transform2dPlugin,
+    };
+
+    #[test]
+    fn interpolate_with_overrides() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            Transform2dPlugin,
+            Transform2dInterpolationPlugin,
+        ))
+        .init_resource::<Time<Fixed>>();
+
+        let entity = app
+            .world
+            .spawn((
+                Transform2dBundle::from_transform(Transform2d::from_xy(10., 10.)),
+                Transform2dInterpolation::default(),
+                YSort::default(),
+            ))
+            .id();
+        app.update();
+
+        *app.world
+            .get_mut::<Transform2dInterpolation>(entity)
+            .unwrap() = Transform2dInterpolation {
+            previous: Transform2d::from_xy(0., 10.),
+            current: Transform2d::from_xy(10., 10.),
+        };
+        app.update();
+
+        let z = app.world.resource::<YSortConfig>().z_at(10.);
+        assert_eq!(
+            app.world.get::<Transform>(entity).unwrap().translation,
+            Vec3::new(0., 10., z)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+
+use crate::{global_transform2d::GlobalTransform2d, transform2d::Transform2d, y_sort::YSortConfig};
+
+/// Treats the [`Transform2d`] of an [`Entity`] as a position on the ground of an isometric or dimetric scene.
+///
+/// The translation is projected onto the screen with the [`IsometricProjection`] resource when it's written into the [`Transform`],
+/// so gameplay can use plain orthogonal math on the [`Transform2d`].
+/// A z is computed from the projected `Y` using the [`YSortConfig`] resource, and added to the `z_translation`,
+/// so entities further back are drawn behind. Don't combine this with a [`YSort`](crate::y_sort::YSort).
+///
+/// Pure 2D entities have their [`GlobalTransform`] projected.
+/// Entities with a [`Transform`] are only projected if they don't have a [`Parent`], the [`Transform`] of a child is relative to its parent on the screen.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Isometric;
+
+/// The projection from ground coordinates onto the screen used for entities with an [`Isometric`] component.
+///
+/// The rotation and scale of the [`Transform2d`] are not projected.
+#[derive(Resource, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct IsometricProjection {
+    /// The screen-space vector that one unit along the ground `X` axis is projected onto.
+    pub x_axis: Vec2,
+    /// The screen-space vector that one unit along the ground `Y` axis is projected onto.
+    pub y_axis: Vec2,
+}
+
+impl Default for IsometricProjection {
+    fn default() -> Self {
+        IsometricProjection::dimetric(Vec2::new(2., 1.))
+    }
+}
+
+impl IsometricProjection {
+    /// Creates a new [`IsometricProjection`] with the screen-space `x_axis` and `y_axis` of the ground.
+    ///
+    /// The axes must not be parallel.
+    #[inline]
+    pub const fn new(x_axis: Vec2, y_axis: Vec2) -> Self {
+        IsometricProjection { x_axis, y_axis }
+    }
+
+    /// Creates a new [`IsometricProjection`] where a ground tile of one unit is drawn as a diamond of `tile_size` on the screen.
+    ///
+    /// The ground `X` axis points to the bottom right of the screen and the ground `Y` axis to the top right.
+    /// The common 2:1 pixel art projection has a `tile_size` twice as wide as it is high.
+    #[inline]
+    pub fn dimetric(tile_size: Vec2) -> Self {
+        let half = tile_size / 2.;
+        IsometricProjection::new(Vec2::new(half.x, -half.y), half)
+    }
+
+    /// Creates a new true isometric [`IsometricProjection`], where a ground tile of one unit is drawn `tile_width` wide,
+    /// and the ground axes are 30 degrees from the horizontal.
+    #[inline]
+    pub fn isometric(tile_width: f32) -> Self {
+        IsometricProjection::dimetric(Vec2::new(tile_width, tile_width / 3f32.sqrt()))
+    }
+
+    /// Projects a `point` on the ground onto the screen.
+    #[inline]
+    pub fn project(&self, point: Vec2) -> Vec2 {
+        self.x_axis * point.x + self.y_axis * point.y
+    }
+
+    /// Projects a `point` on the screen back onto the ground.
+    #[inline]
+    pub fn unproject(&self, point: Vec2) -> Vec2 {
+        Mat2::from_cols(self.x_axis, self.y_axis).inverse() * point
+    }
+
+    /// Returns `transform` with its translation projected onto the screen and the z of its depth added.
+    #[inline]
+    pub fn project_transform(
+        &self,
+        mut transform: Transform2d,
+        config: &YSortConfig,
+    ) -> Transform2d {
+        transform.translation = self.project(transform.translation);
+        transform.z_translation += config.z_at(transform.translation.y);
+        transform
+    }
+
+    /// Returns `global_transform` with its translation projected onto the screen and the z of its depth added.
+    #[inline]
+    pub fn project_global_transform(
+        &self,
+        global_transform: GlobalTransform2d,
+        config: &YSortConfig,
+    ) -> GlobalTransform2d {
+        let translation = self.project(global_transform.translation());
+        let offset =
+            GlobalTransform2d::from_translation(translation - global_transform.translation())
+                .with_z_translation(config.z_at(translation.y));
+        offset * global_transform
+    }
+
+    /// Returns `transform` with its translation projected back onto the ground and the z of its depth removed.
+    ///
+    /// This is the inverse of [`IsometricProjection::project_transform`].
+    #[inline]
+    pub fn unproject_transform(
+        &self,
+        mut transform: Transform2d,
+        config: &YSortConfig,
+    ) -> Transform2d {
+        transform.z_translation -= config.z_at(transform.translation.y);
+        transform.translation = self.unproject(transform.translation);
+        transform
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project() {
+        let projection = IsometricProjection::dimetric(Vec2::new(64., 32.));
+        let config = YSortConfig::default();
+
+        assert_eq!(projection.project(Vec2::X), Vec2::new(32., -16.));
+        assert_eq!(projection.project(Vec2::Y), Vec2::new(32., 16.));
+        assert_eq!(projection.project(Vec2::ONE), Vec2::new(64., 0.));
+
+        // Further back on the ground is drawn behind.
+        let front = projection.project_transform(Transform2d::from_xy(1., 0.), &config);
+        let back = projection.project_transform(Transform2d::from_xy(0., 1.), &config);
+        assert!(back.z_translation < front.z_translation);
+
+        let transform = Transform2d::from_xyz(3., -2., 1.).with_rotation(0.5);
+        let round_trip = projection
+            .unproject_transform(projection.project_transform(transform, &config), &config);
+        assert!(round_trip
+            .translation
+            .abs_diff_eq(transform.translation, 1e-5));
+        assert!((round_trip.z_translation - transform.z_translation).abs() < 1e-6);
+        assert_eq!(round_trip.rotation, transform.rotation);
+    }
+}
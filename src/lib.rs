@@ -2,21 +2,79 @@
 
 use bevy::{prelude::*, transform::TransformSystem};
 
+pub mod affine_transform2d;
 pub mod bundle;
+pub mod dtransform2d;
+pub mod fixed;
+pub mod fixed_transform2d;
+pub mod flip;
+pub mod floating_origin;
+pub mod global_transform2d;
+pub mod grid_transform2d;
+pub mod inheritance;
+pub mod interpolation;
+pub mod isometric;
+pub mod pivot;
+pub mod pixel_snap;
+pub mod planar;
+pub mod plane;
+pub mod rot2;
+#[cfg(feature = "serde")]
+pub mod serde_degrees;
 pub mod systems;
 pub mod transform2d;
+pub mod y_sort;
+pub mod z_order;
 
+use affine_transform2d::AffineTransform2d;
+use dtransform2d::DTransform2d;
+use fixed_transform2d::FixedTransform2d;
+use flip::Flip2d;
+use floating_origin::{FloatingOrigin2d, FloatingOriginFocus, OriginRebased2d};
+use global_transform2d::GlobalTransform2d;
+use grid_transform2d::{Grid2dConfig, GridTransform2d};
+use inheritance::Transform2dInheritance;
+use isometric::{Isometric, IsometricProjection};
+use pivot::Pivot2d;
+use pixel_snap::{PixelSnap, PixelSnapCamera};
+use planar::PlanarProjectionPolicy;
+use plane::Plane2d;
 use transform2d::Transform2d;
+use y_sort::{YSort, YSortConfig};
+use z_order::{ZMode, ZOrder, ZOrderConfig};
 
 pub mod prelude {
     #[cfg(feature = "bevy_render")]
     pub use crate::bundle::Spatial2dBundle;
-    pub use crate::{bundle::Transform2dBundle, transform2d::Transform2d, Transform2dPlugin};
+    pub use crate::{
+        affine_transform2d::AffineTransform2d,
+        bundle::{AffineTransform2dBundle, Pure2dBundle, Transform2dBundle},
+        dtransform2d::DTransform2d,
+        fixed::{FixedAngle, FixedPoint, FixedVec2},
+        fixed_transform2d::FixedTransform2d,
+        flip::Flip2d,
+        floating_origin::{FloatingOrigin2d, FloatingOriginFocus, OriginRebased2d},
+        global_transform2d::GlobalTransform2d,
+        grid_transform2d::{Facing4, Facing8, Grid2dConfig, GridTransform2d},
+        inheritance::Transform2dInheritance,
+        interpolation::{Teleport2d, Transform2dInterpolation, Transform2dInterpolationPlugin},
+        isometric::{Isometric, IsometricProjection},
+        pivot::Pivot2d,
+        pixel_snap::{PixelSnap, PixelSnapCamera},
+        planar::PlanarProjectionPolicy,
+        plane::Plane2d,
+        rot2::Rot2,
+        transform2d::Transform2d,
+        y_sort::{YSort, YSortConfig},
+        z_order::{ZMode, ZOrder, ZOrderConfig},
+        SyncTransform2dTo3d, Transform2dPlugin,
+    };
 }
 
 /// The [`Plugin`] for [`Transform2d`].
 ///
-/// This registers the systems that synchronise [`Transform2d`] with [`Transform`].
+/// This registers the systems that synchronise [`Transform2d`] with [`Transform`],
+/// and the systems that propagate [`Transform2d`] into [`GlobalTransform2d`].
 #[derive(Default)]
 pub struct Transform2dPlugin;
 
@@ -24,23 +82,132 @@ pub struct Transform2dPlugin;
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct Transform2dPropagate;
 
+/// The [`SystemSet`] of the [`sync_transform_2d_to_3d`](systems::sync_transform_2d_to_3d) system added by the [`Transform2dPlugin`].
+///
+/// Order against this set instead of the system itself, as the physics integrations add the system a second time.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct SyncTransform2dTo3d;
+
 impl Plugin for Transform2dPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Transform2d>()
+            .register_type::<AffineTransform2d>()
+            .register_type::<DTransform2d>()
+            .register_type::<FixedTransform2d>()
+            .register_type::<fixed::FixedPoint>()
+            .register_type::<fixed::FixedAngle>()
+            .register_type::<fixed::FixedVec2>()
+            .register_type::<Flip2d>()
+            .register_type::<FloatingOrigin2d>()
+            .register_type::<FloatingOriginFocus>()
+            .register_type::<GlobalTransform2d>()
+            .register_type::<GridTransform2d>()
+            .register_type::<Grid2dConfig>()
+            .register_type::<grid_transform2d::Facing4>()
+            .register_type::<grid_transform2d::Facing8>()
+            .register_type::<Transform2dInheritance>()
+            .register_type::<Pivot2d>()
+            .register_type::<PixelSnap>()
+            .register_type::<PixelSnapCamera>()
+            .register_type::<PlanarProjectionPolicy>()
+            .register_type::<Plane2d>()
+            .register_type::<Isometric>()
+            .register_type::<IsometricProjection>()
+            .register_type::<rot2::Rot2>()
+            .register_type::<YSort>()
+            .register_type::<YSortConfig>()
+            .register_type::<ZMode>()
+            .register_type::<ZOrder>()
+            .register_type::<ZOrderConfig>()
+            .init_resource::<FloatingOrigin2d>()
+            .init_resource::<Grid2dConfig>()
+            .init_resource::<PlanarProjectionPolicy>()
+            .init_resource::<Plane2d>()
+            .init_resource::<IsometricProjection>()
+            .init_resource::<YSortConfig>()
+            .init_resource::<ZOrderConfig>()
+            .add_event::<OriginRebased2d>()
             // Add transform2d sync system to startup so the first update is "correct"
             .add_systems(
                 PostStartup,
-                systems::sync_transform_2d_to_3d
+                (
+                    (
+                        systems::sync_dtransform_2d_to_2d,
+                        systems::sync_fixed_transform_2d_to_2d,
+                        systems::sync_grid_transform_2d_to_2d,
+                        systems::sync_z_order_to_2d,
+                    )
+                        .chain(),
+                    (
+                        systems::sync_transform_2d_to_3d.in_set(SyncTransform2dTo3d),
+                        (
+                            systems::propagate_transform_2d,
+                            systems::sync_global_transform_2d_to_3d,
+                        )
+                            .chain(),
+                    ),
+                )
+                    .chain()
                     .before(TransformSystem::TransformPropagate)
                     .in_set(Transform2dPropagate),
             )
             .add_systems(
                 PostUpdate,
-                systems::sync_transform_2d_to_3d
+                (
+                    (
+                        systems::sync_dtransform_2d_to_2d,
+                        systems::sync_fixed_transform_2d_to_2d,
+                        systems::sync_grid_transform_2d_to_2d,
+                        systems::sync_z_order_to_2d,
+                    )
+                        .chain(),
+                    (
+                        systems::sync_transform_2d_to_3d.in_set(SyncTransform2dTo3d),
+                        (
+                            systems::propagate_transform_2d,
+                            systems::sync_global_transform_2d_to_3d,
+                        )
+                            .chain(),
+                    ),
+                )
+                    .chain()
+                    .before(TransformSystem::TransformPropagate)
+                    .in_set(Transform2dPropagate),
+            )
+            .add_systems(
+                PostStartup,
+                systems::sync_propagation_overrides_to_3d
+                    .after(SyncTransform2dTo3d)
+                    .after(systems::propagate_transform_2d)
                     .before(TransformSystem::TransformPropagate)
                     .in_set(Transform2dPropagate),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    floating_origin::rebase_floating_origin
+                        .before(systems::sync_dtransform_2d_to_2d),
+                    systems::sync_propagation_overrides_to_3d
+                        .after(SyncTransform2dTo3d)
+                        .after(systems::propagate_transform_2d)
+                        .before(TransformSystem::TransformPropagate),
+                    // The sync tick only needs to be present by the next time the sync systems run
+                    systems::insert_sync_ticks,
+                )
+                    .in_set(Transform2dPropagate),
             );
 
+        #[cfg(debug_assertions)]
+        app.add_systems(
+            PostUpdate,
+            (
+                systems::warn_z_order_overlap.after(systems::propagate_transform_2d),
+                systems::warn_transform_under_pure_2d_parent,
+                systems::warn_transform_on_affine_entity,
+            )
+                .in_set(Transform2dPropagate),
+        );
+
         #[cfg(feature = "bevy_rapier2d")]
         {
             use bevy_rapier2d::plugin::{
@@ -55,7 +222,8 @@ impl Plugin for Transform2dPlugin {
                         .before(RapierTransformPropagateSet),
                     systems::sync_transform_3d_to_2d
                         .in_set(PhysicsSet::Writeback)
-                        .after(writeback_rigid_bodies),
+                        .after(writeback_rigid_bodies)
+                        .before(systems::propagate_transform_2d),
                 ),
             );
         }
@@ -68,9 +236,72 @@ impl Plugin for Transform2dPlugin {
                 PostUpdate,
                 (
                     systems::sync_transform_2d_to_3d.in_set(PhysicsSet::Prepare),
-                    systems::sync_transform_3d_to_2d.after(PhysicsSet::Sync),
+                    systems::sync_transform_3d_to_2d
+                        .after(PhysicsSet::Sync)
+                        .before(systems::propagate_transform_2d),
                 ),
             );
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpolation::Transform2dInterpolationPlugin;
+
+    #[test]
+    fn sync_system_added_twice() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            Transform2dPlugin,
+            Transform2dInterpolationPlugin,
+        ))
+        .init_resource::<Time<Fixed>>()
+        // The physics integrations add the sync system to `PostUpdate` a second time.
+        .add_systems(PostUpdate, systems::sync_transform_2d_to_3d);
+
+        let entity = app
+            .world
+            .spawn((
+                bundle::Transform2dBundle::from_transform(Transform2d::from_xy(1., 2.)),
+                YSort::default(),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(
+            app.world
+                .get::<GlobalTransform>(entity)
+                .unwrap()
+                .translation()
+                .truncate(),
+            Vec2::new(1., 2.)
+        );
+    }
+
+    #[test]
+    fn overrides_at_startup() {
+        let mut app = App::new();
+        app.add_plugins((TransformPlugin, Transform2dPlugin));
+
+        let entity = app
+            .world
+            .spawn((
+                bundle::Transform2dBundle::from_transform(Transform2d::from_xy(1., 2.)),
+                YSort::default(),
+            ))
+            .id();
+        app.world.run_schedule(PostStartup);
+
+        let z = app.world.resource::<YSortConfig>().z_at(2.);
+        assert_eq!(
+            app.world
+                .get::<GlobalTransform>(entity)
+                .unwrap()
+                .translation(),
+            Vec3::new(1., 2., z)
+        );
+    }
+}
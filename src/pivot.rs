@@ -0,0 +1,65 @@
+use bevy::{math::Affine2, prelude::*};
+
+use crate::global_transform2d::GlobalTransform2d;
+
+/// The point in local space that an [`Entity`] rotates and scales around.
+///
+/// With a pivot the [`Transform2d::translation`](crate::transform2d::Transform2d::translation) is where the pivot is,
+/// and the entity is drawn offset from it, like a door around its hinge or a sword around its handle.
+/// The offset is applied to the [`GlobalTransform2d`] and the [`Transform`] used for rendering,
+/// so children are relative to the origin of the entity rather than its pivot.
+#[derive(Component, Debug, Default, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Pivot2d(pub Vec2);
+
+impl Pivot2d {
+    /// Creates a new [`Pivot2d`] from a point normalized to `size`,
+    /// where `(-0.5, -0.5)` is the bottom left corner and `(0.5, 0.5)` is the top right corner,
+    /// like a sprite's [`Anchor`](https://docs.rs/bevy/0.12/bevy/sprite/enum.Anchor.html).
+    #[inline]
+    pub fn normalized(point: Vec2, size: Vec2) -> Self {
+        Pivot2d(point * size)
+    }
+
+    /// Returns `global_transform` offset so its pivot ends up where its origin was.
+    #[inline]
+    pub fn apply(&self, global_transform: GlobalTransform2d) -> GlobalTransform2d {
+        GlobalTransform2d::from(global_transform.affine() * Affine2::from_translation(-self.0))
+            .with_z_translation(global_transform.z_translation())
+    }
+
+    /// Returns `global_transform` with the offset of [`Pivot2d::apply`] removed.
+    #[inline]
+    pub fn unapply(&self, global_transform: GlobalTransform2d) -> GlobalTransform2d {
+        GlobalTransform2d::from(global_transform.affine() * Affine2::from_translation(self.0))
+            .with_z_translation(global_transform.z_translation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use crate::transform2d::Transform2d;
+
+    use super::*;
+
+    #[test]
+    fn rotate_around_pivot() {
+        let pivot = Pivot2d::normalized(Vec2::new(-0.5, 0.), Vec2::new(20., 10.));
+        assert_eq!(pivot.0, Vec2::new(-10., 0.));
+
+        let transform = Transform2d::from_xy(5., 5.).with_rotation(FRAC_PI_2);
+        let global_transform = pivot.apply(GlobalTransform2d::from(transform));
+
+        // The pivot stays in place, and the center swings around it.
+        assert!(global_transform
+            .transform_point(pivot.0)
+            .abs_diff_eq(Vec2::new(5., 5.), 1e-5));
+        assert!(global_transform
+            .translation()
+            .abs_diff_eq(Vec2::new(5., 15.), 1e-5));
+    }
+}
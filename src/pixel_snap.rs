@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+
+use crate::transform2d::Transform2d;
+
+/// Rounds the [`Transform`] written from the [`Transform2d`] of an [`Entity`] to a pixel grid,
+/// which stops pixel art from shimmering when it moves by fractions of a pixel.
+///
+/// Only the rendered [`Transform`] is snapped, the [`Transform2d`] keeps its full precision.
+/// If an entity has a [`PixelSnapCamera`], the grid is relative to its translation.
+/// The translation of a child is snapped relative to its [`Parent`] instead,
+/// so it stays on the grid if its parent is snapped too and isn't rotated or scaled.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct PixelSnap {
+    /// The size of a pixel in world units.
+    pub pixel_size: f32,
+    /// The increment in radians the rotation is rounded to. The rotation is not snapped if this is `0.`.
+    pub rotation_step: f32,
+}
+
+impl Default for PixelSnap {
+    fn default() -> Self {
+        PixelSnap::new(1.)
+    }
+}
+
+impl PixelSnap {
+    /// Creates a new [`PixelSnap`] with a `pixel_size`, that doesn't snap the rotation.
+    #[inline]
+    pub const fn new(pixel_size: f32) -> Self {
+        PixelSnap {
+            pixel_size,
+            rotation_step: 0.,
+        }
+    }
+
+    /// Returns this [`PixelSnap`] with a new rotation step.
+    #[must_use]
+    #[inline]
+    pub const fn with_rotation_step(mut self, rotation_step: f32) -> Self {
+        self.rotation_step = rotation_step;
+        self
+    }
+
+    /// Returns `transform` with its translation rounded to the pixel grid around `origin`,
+    /// and its rotation rounded to the rotation step.
+    #[inline]
+    pub fn snap(&self, mut transform: Transform2d, origin: Vec2) -> Transform2d {
+        transform.translation =
+            origin + ((transform.translation - origin) / self.pixel_size).round() * self.pixel_size;
+        if self.rotation_step != 0. {
+            transform.rotation =
+                (transform.rotation / self.rotation_step).round() * self.rotation_step;
+        }
+        transform
+    }
+}
+
+/// Marks the camera that the [`PixelSnap`] grid moves with.
+///
+/// The camera should not have a [`Parent`]. If more than one entity has this component the grid stays at the world origin.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct PixelSnapCamera;
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_4;
+
+    use super::*;
+
+    #[test]
+    fn snap() {
+        let snap = PixelSnap::new(0.5).with_rotation_step(FRAC_PI_4);
+        let transform = Transform2d::from_xy(1.3, -0.7).with_rotation(0.9);
+
+        let snapped = snap.snap(transform, Vec2::ZERO);
+        assert_eq!(snapped.translation, Vec2::new(1.5, -0.5));
+        assert_eq!(snapped.rotation, FRAC_PI_4);
+
+        let snapped = snap.snap(transform, Vec2::new(0.2, 0.2));
+        assert!(snapped.translation.abs_diff_eq(Vec2::new(1.2, -0.8), 1e-6));
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+/// What [`sync_transform_3d_to_2d`](crate::systems::sync_transform_3d_to_2d) does with a [`Transform`]
+/// that's rotated out of the `XY` plane or scaled along `Z`, for example by a third-party plugin.
+///
+/// See [`Transform2d::try_from_transform`](crate::transform2d::Transform2d::try_from_transform).
+#[derive(Resource, Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum PlanarProjectionPolicy {
+    /// Silently drop the rotation around the `X` and `Y` axes and the `Z` scale.
+    #[default]
+    Ignore,
+    /// Like [`PlanarProjectionPolicy::Ignore`], but log a warning the first time it happens to each entity.
+    WarnOnce,
+    /// Project the rotation onto the `XY` plane, keeping the direction the local `X` axis points in.
+    /// The `Z` scale is dropped.
+    ///
+    /// See [`Transform2d::from_transform_projected`](crate::transform2d::Transform2d::from_transform_projected).
+    Project,
+    /// Panic in debug builds. Behaves like [`PlanarProjectionPolicy::Ignore`] in release builds.
+    PanicInDebug,
+}
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+
+/// The plane in 3D space that [`Transform2d`](crate::transform2d::Transform2d)s are mapped onto when synchronised with [`Transform`]s.
+///
+/// The `X` and `Y` axes of the [`Transform2d`](crate::transform2d::Transform2d) are mapped onto the `X` and `Y` axes of the plane,
+/// and `z_translation` is mapped onto its normal.
+/// Only entities without a [`Parent`] are mapped, the [`Transform`] of a child is relative to its parent which is already on the plane.
+///
+/// This is a setting of the [`Transform2dPlugin`](crate::Transform2dPlugin), insert it as a resource to change it.
+/// The physics integrations only work with [`Plane2d::XY`].
+#[derive(Resource, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Plane2d {
+    /// The rotation from the `XY` plane onto this plane.
+    rotation: Quat,
+}
+
+impl Default for Plane2d {
+    fn default() -> Self {
+        Plane2d::XY
+    }
+}
+
+impl Plane2d {
+    /// The `XY` plane, where `z_translation` is the depth towards the camera of a [`Camera2dBundle`](https://docs.rs/bevy/0.12/bevy/core_pipeline/core_2d/struct.Camera2dBundle.html).
+    pub const XY: Self = Plane2d {
+        rotation: Quat::IDENTITY,
+    };
+
+    /// The `XZ` ground plane, where the `Y` axis of the [`Transform2d`](crate::transform2d::Transform2d) points along `-Z`
+    /// and `z_translation` is the height along `Y`.
+    pub const XZ: Self = Plane2d {
+        // A rotation of -90 degrees around the `X` axis.
+        rotation: Quat::from_xyzw(-0.70710677, 0., 0., 0.70710677),
+    };
+
+    /// Creates a new [`Plane2d`] with the given `x_axis` and `y_axis`.
+    /// `z_translation` is mapped onto their cross product.
+    ///
+    /// The axes are expected to be normalized and orthogonal to each other.
+    #[inline]
+    pub fn from_axes(x_axis: Vec3, y_axis: Vec3) -> Self {
+        Plane2d {
+            rotation: Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, x_axis.cross(y_axis))),
+        }
+    }
+
+    /// Returns the rotation from the `XY` plane onto this plane.
+    #[inline]
+    pub fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    /// Get the unit vector in 3D space that the `X` axis is mapped onto.
+    #[inline]
+    pub fn x_axis(&self) -> Vec3 {
+        self.rotation * Vec3::X
+    }
+
+    /// Get the unit vector in 3D space that the `Y` axis is mapped onto.
+    #[inline]
+    pub fn y_axis(&self) -> Vec3 {
+        self.rotation * Vec3::Y
+    }
+
+    /// Get the unit vector in 3D space that `z_translation` is mapped onto.
+    #[inline]
+    pub fn normal(&self) -> Vec3 {
+        self.rotation * Vec3::Z
+    }
+
+    /// Maps a `transform` in the `XY` plane onto this plane.
+    #[inline]
+    pub fn transform_to_3d(&self, transform: Transform) -> Transform {
+        if *self == Plane2d::XY {
+            return transform;
+        }
+        Transform {
+            translation: self.rotation * transform.translation,
+            rotation: self.rotation * transform.rotation,
+            scale: transform.scale,
+        }
+    }
+
+    /// Maps a `transform` on this plane back onto the `XY` plane.
+    #[inline]
+    pub fn transform_from_3d(&self, transform: Transform) -> Transform {
+        if *self == Plane2d::XY {
+            return transform;
+        }
+        let inverse = self.rotation.inverse();
+        Transform {
+            translation: inverse * transform.translation,
+            rotation: inverse * transform.rotation,
+            scale: transform.scale,
+        }
+    }
+
+    /// Maps a `global_transform` in the `XY` plane onto this plane.
+    #[inline]
+    pub fn global_transform_to_3d(&self, global_transform: GlobalTransform) -> GlobalTransform {
+        if *self == Plane2d::XY {
+            return global_transform;
+        }
+        GlobalTransform::from(Transform::from_rotation(self.rotation)) * global_transform
+    }
+
+    /// Returns the plane used for an entity, which is `self` for entities without a parent and [`Plane2d::XY`] otherwise.
+    #[inline]
+    pub(crate) fn for_entity(&self, has_parent: bool) -> Plane2d {
+        if has_parent {
+            Plane2d::XY
+        } else {
+            *self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use crate::transform2d::Transform2d;
+
+    use super::*;
+
+    #[test]
+    fn xz() {
+        assert!(Plane2d::XZ
+            .rotation()
+            .abs_diff_eq(Quat::from_rotation_x(-FRAC_PI_2), 1e-6));
+        let plane = Plane2d::from_axes(Vec3::X, Vec3::NEG_Z);
+        assert!(plane.x_axis().abs_diff_eq(Plane2d::XZ.x_axis(), 1e-6));
+        assert!(plane.normal().abs_diff_eq(Vec3::Y, 1e-6));
+
+        let transform_2d = Transform2d::from_xyz(1., 2., 3.).with_rotation(FRAC_PI_2);
+        let transform = Plane2d::XZ.transform_to_3d(transform_2d.into());
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1., 3., -2.), 1e-6));
+        // Forward in 2D is forward on the ground.
+        assert!((transform.rotation * Vec3::X).abs_diff_eq(Vec3::NEG_Z, 1e-6));
+
+        let round_trip = Transform2d::from(Plane2d::XZ.transform_from_3d(transform));
+        assert!(round_trip
+            .translation
+            .abs_diff_eq(transform_2d.translation, 1e-6));
+        assert!((round_trip.rotation - transform_2d.rotation).abs() < 1e-6);
+        assert!((round_trip.z_translation - transform_2d.z_translation).abs() < 1e-6);
+    }
+}
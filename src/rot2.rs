@@ -0,0 +1,284 @@
+use std::ops::{Mul, MulAssign};
+
+use bevy::prelude::*;
+
+/// A 2D rotation stored as a unit complex number, `(cos, sin)`.
+///
+/// Unlike an angle in radians, composing [`Rot2`]s never grows without bound,
+/// and rotating vectors doesn't need to call [`f32::sin_cos`] every time.
+#[derive(Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Rot2 {
+    /// The cosine of the rotation angle.
+    pub cos: f32,
+    /// The sine of the rotation angle.
+    pub sin: f32,
+}
+
+impl Default for Rot2 {
+    fn default() -> Self {
+        Rot2::IDENTITY
+    }
+}
+
+impl Rot2 {
+    /// No rotation.
+    pub const IDENTITY: Self = Rot2 { cos: 1., sin: 0. };
+
+    /// Creates a [`Rot2`] from an angle in radians. Positive values rotate anti-clockwise.
+    #[inline]
+    pub fn radians(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Rot2 { cos, sin }
+    }
+
+    /// Creates a [`Rot2`] from an angle in degrees. Positive values rotate anti-clockwise.
+    #[inline]
+    pub fn degrees(degrees: f32) -> Self {
+        Self::radians(degrees.to_radians())
+    }
+
+    /// Creates a [`Rot2`] from the sine and cosine of an angle.
+    ///
+    /// The values are expected to be normalized, `sin * sin + cos * cos == 1.`.
+    #[inline]
+    pub fn from_sin_cos(sin: f32, cos: f32) -> Self {
+        Rot2 { cos, sin }
+    }
+
+    /// Creates the [`Rot2`] that rotates `from` to point in the same direction as `to`.
+    ///
+    /// Returns [`Rot2::IDENTITY`] if either vector is zero.
+    #[inline]
+    pub fn from_to(from: Vec2, to: Vec2) -> Self {
+        Rot2 {
+            cos: from.dot(to),
+            sin: from.perp_dot(to),
+        }
+        .try_normalize()
+        .unwrap_or(Rot2::IDENTITY)
+    }
+
+    /// Returns the angle in radians, in the range `(-PI, PI]`.
+    #[inline]
+    pub fn as_radians(self) -> f32 {
+        self.sin.atan2(self.cos)
+    }
+
+    /// Returns the angle in degrees, in the range `(-180, 180]`.
+    #[inline]
+    pub fn as_degrees(self) -> f32 {
+        self.as_radians().to_degrees()
+    }
+
+    /// Returns the length of the underlying complex number.
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.cos.hypot(self.sin)
+    }
+
+    /// Returns `true` if the length of the underlying complex number is `1.` within a small tolerance.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        (self.cos * self.cos + self.sin * self.sin - 1.).abs() <= 1e-4
+    }
+
+    /// Returns this rotation with a length of `1.`.
+    ///
+    /// Use this to counter the drift that builds up when composing many rotations.
+    /// The result is not finite if the length is zero.
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        let length_recip = self.length().recip();
+        Rot2 {
+            cos: self.cos * length_recip,
+            sin: self.sin * length_recip,
+        }
+    }
+
+    /// Returns this rotation with a length of `1.`, or `None` if the length is zero or not finite.
+    #[inline]
+    #[must_use]
+    pub fn try_normalize(self) -> Option<Self> {
+        let length_recip = self.length().recip();
+        (length_recip.is_finite() && length_recip > 0.).then_some(Rot2 {
+            cos: self.cos * length_recip,
+            sin: self.sin * length_recip,
+        })
+    }
+
+    /// Returns the inverse rotation, rotating by the same angle in the opposite direction.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        Rot2 {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Rotates the given `vector`.
+    #[inline]
+    pub fn rotate(self, vector: Vec2) -> Vec2 {
+        Vec2::new(
+            self.cos * vector.x - self.sin * vector.y,
+            self.sin * vector.x + self.cos * vector.y,
+        )
+    }
+
+    /// Returns the angle in radians between `self` and `end`, taking the shortest arc.
+    ///
+    /// The result is in the range `(-PI, PI]`.
+    #[inline]
+    pub fn angle_to(self, end: Self) -> f32 {
+        (self.inverse() * end).as_radians()
+    }
+
+    /// Spherically interpolates between `self` and `end` along the shortest arc.
+    ///
+    /// When `t` is `0.` the result is `self`, when `t` is `1.` the result is `end`.
+    #[inline]
+    #[must_use]
+    pub fn slerp(self, end: Self, t: f32) -> Self {
+        self * Rot2::radians(self.angle_to(end) * t)
+    }
+
+    /// Get the unit vector in the local `X` direction.
+    #[inline]
+    pub fn local_x(self) -> Vec2 {
+        Vec2::new(self.cos, self.sin)
+    }
+
+    /// Get the unit vector in the local `Y` direction.
+    #[inline]
+    pub fn local_y(self) -> Vec2 {
+        Vec2::new(-self.sin, self.cos)
+    }
+
+    /// Returns the rotation matrix of this rotation.
+    #[inline]
+    pub fn to_mat2(self) -> Mat2 {
+        Mat2::from_cols(self.local_x(), self.local_y())
+    }
+}
+
+impl From<f32> for Rot2 {
+    #[inline]
+    fn from(radians: f32) -> Self {
+        Rot2::radians(radians)
+    }
+}
+
+impl From<Rot2> for f32 {
+    #[inline]
+    fn from(rotation: Rot2) -> Self {
+        rotation.as_radians()
+    }
+}
+
+impl From<Rot2> for Mat2 {
+    #[inline]
+    fn from(rotation: Rot2) -> Self {
+        rotation.to_mat2()
+    }
+}
+
+impl Mul<Rot2> for Rot2 {
+    type Output = Rot2;
+
+    /// Composes two rotations, rotating by the sum of their angles.
+    #[inline]
+    fn mul(self, rhs: Rot2) -> Self::Output {
+        Rot2 {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+
+impl MulAssign<Rot2> for Rot2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Rot2) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Vec2> for Rot2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+/// Types that can be used as a rotation by [`Transform2d`](crate::transform2d::Transform2d).
+pub trait IntoRotation {
+    /// Returns the rotation in radians.
+    fn into_rotation(self) -> f32;
+
+    /// Returns the rotation as a [`Rot2`].
+    #[inline]
+    fn into_rot2(self) -> Rot2
+    where
+        Self: Sized,
+    {
+        Rot2::radians(self.into_rotation())
+    }
+}
+
+impl IntoRotation for f32 {
+    fn into_rotation(self) -> f32 {
+        self
+    }
+}
+
+impl IntoRotation for Rot2 {
+    fn into_rotation(self) -> f32 {
+        self.as_radians()
+    }
+
+    #[inline]
+    fn into_rot2(self) -> Rot2 {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+
+    #[test]
+    fn compose_and_inverse() {
+        let a = Rot2::radians(2.5);
+        let b = Rot2::radians(1.5);
+
+        // 4 radians wraps to 4 - TAU.
+        assert!(((a * b).as_radians() - (4. - 2. * PI)).abs() < 1e-5);
+        assert!((a * a.inverse()).local_x().abs_diff_eq(Vec2::X, 1e-6));
+        assert!((a * Vec2::X).abs_diff_eq(a.local_x(), 1e-6));
+        assert!(a.to_mat2().abs_diff_eq(Mat2::from_angle(2.5), 1e-6));
+    }
+
+    #[test]
+    fn slerp_takes_shortest_arc() {
+        let start = Rot2::degrees(170.);
+        let end = Rot2::degrees(-170.);
+
+        assert!((start.slerp(end, 0.5).as_degrees().abs() - 180.).abs() < 1e-3);
+        assert!((start.slerp(end, 0.25).as_degrees() - 175.).abs() < 1e-3);
+        assert!((start.angle_to(end).to_degrees() - 20.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn from_to() {
+        let rotation = Rot2::from_to(Vec2::Y, Vec2::new(-2., 0.));
+        assert!((rotation.as_radians() - FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(Rot2::from_to(Vec2::ZERO, Vec2::X), Rot2::IDENTITY);
+    }
+}
@@ -0,0 +1,74 @@
+//! Serializes a [`Transform2d`] with its rotation in degrees instead of radians.
+//!
+//! Use this with serde's `with` attribute for files that are meant to be read and edited by hand.
+//!
+//! ```
+//! # use bevy_mod_transform2d::prelude::*;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct SpawnPoint {
+//!     #[serde(with = "bevy_mod_transform2d::serde_degrees")]
+//!     transform: Transform2d,
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::transform2d::Transform2d;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Transform2d")]
+struct Transform2dDegrees {
+    translation: Vec2,
+    /// The rotation in degrees.
+    rotation: f32,
+    scale: Vec2,
+    z_translation: f32,
+}
+
+/// Serializes `transform` with its rotation in degrees.
+pub fn serialize<S: Serializer>(transform: &Transform2d, serializer: S) -> Result<S::Ok, S::Error> {
+    Transform2dDegrees {
+        translation: transform.translation,
+        rotation: transform.rotation.to_degrees(),
+        scale: transform.scale,
+        z_translation: transform.z_translation,
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a [`Transform2d`] with its rotation in degrees.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Transform2d, D::Error> {
+    let transform = Transform2dDegrees::deserialize(deserializer)?;
+    Ok(Transform2d {
+        translation: transform.translation,
+        rotation: transform.rotation.to_radians(),
+        scale: transform.scale,
+        z_translation: transform.z_translation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Degrees(#[serde(with = "super")] Transform2d);
+
+    #[test]
+    fn roundtrip() {
+        let transform = Transform2d::from_xyz(1., 2., 3.)
+            .with_rotation(90f32.to_radians())
+            .with_scale(Vec2::splat(2.));
+
+        let serialized = ron::to_string(&Degrees(transform)).unwrap();
+        assert!(serialized.contains("rotation:90.0"), "{serialized}");
+
+        let Degrees(deserialized) = ron::from_str(&serialized).unwrap();
+        assert!((deserialized.rotation - transform.rotation).abs() < 1e-6);
+        assert_eq!(deserialized.translation, transform.translation);
+
+        let radians: Transform2d = ron::from_str(&ron::to_string(&transform).unwrap()).unwrap();
+        assert_eq!(radians, transform);
+    }
+}
@@ -1,21 +1,1558 @@
-use bevy::prelude::*;
+use bevy::{
+    ecs::{
+        component::Tick,
+        query::{AnyOf, WorldQuery},
+        system::{SystemChangeTick, SystemParam},
+    },
+    prelude::*,
+    utils::HashSet,
+};
 
-use crate::transform2d::Transform2d;
+use crate::{
+    affine_transform2d::AffineTransform2d,
+    dtransform2d::DTransform2d,
+    fixed_transform2d::FixedTransform2d,
+    flip::Flip2d,
+    floating_origin::FloatingOrigin2d,
+    global_transform2d::GlobalTransform2d,
+    grid_transform2d::{Grid2dConfig, GridTransform2d},
+    inheritance::Transform2dInheritance,
+    isometric::{Isometric, IsometricProjection},
+    pivot::Pivot2d,
+    pixel_snap::{PixelSnap, PixelSnapCamera},
+    planar::PlanarProjectionPolicy,
+    plane::Plane2d,
+    transform2d::Transform2d,
+    y_sort::{YSort, YSortConfig},
+    z_order::{ZMode, ZOrder, ZOrderConfig},
+};
 
-// FIXME: Pretty sure the Changed filter won't help when both these systems are running as one will trigger change detection for the other.
+/// Remembers the tick at which [`Transform2d`] and [`Transform`] were last synchronised.
+///
+/// When one of the sync systems writes to one side, the other side is left with an older change tick.
+/// Comparing against this tick lets the sync systems ignore their own writes,
+/// so [`sync_transform_2d_to_3d`] and [`sync_transform_3d_to_2d`] don't keep triggering each other.
+///
+/// This is inserted automatically on entities that have both a [`Transform2d`] and a [`Transform`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Transform2dSyncTick(Tick);
+
+impl Default for Transform2dSyncTick {
+    fn default() -> Self {
+        Transform2dSyncTick(Tick::new(0))
+    }
+}
+
+impl Transform2dSyncTick {
+    /// The tick at which [`Transform2d`] and [`Transform`] were last synchronised.
+    #[inline]
+    pub fn get(&self) -> Tick {
+        self.0
+    }
+
+    /// Marks `Transform2d` and `Transform` as synchronised at `tick`.
+    #[inline]
+    pub(crate) fn set(&mut self, tick: Tick) {
+        self.0 = tick;
+    }
+}
+
+/// Inserts a [`Transform2dSyncTick`] on entities that have both a [`Transform2d`] and a [`Transform`].
+pub fn insert_sync_ticks(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (
+            With<Transform2d>,
+            With<Transform>,
+            Without<Transform2dSyncTick>,
+        ),
+    >,
+) {
+    for entity in &query {
+        commands
+            .entity(entity)
+            .insert(Transform2dSyncTick::default());
+    }
+}
+
+/// Returns whether a [`Transform2d`] or [`Transform`] last changed at `last_changed` hasn't been synchronised into the other yet.
+fn is_unsynced(
+    last_changed: Tick,
+    sync_tick: Option<&Transform2dSyncTick>,
+    system_ticks: &SystemChangeTick,
+) -> bool {
+    let last_synced = sync_tick.map_or(system_ticks.last_run(), Transform2dSyncTick::get);
+    last_changed.is_newer_than(last_synced, system_ticks.this_run())
+}
+
+/// Returns the origin of the [`PixelSnap`] grid, and whether it has moved.
+fn pixel_snap_origin(
+    camera_query: &Query<Ref<Transform2d>, With<PixelSnapCamera>>,
+) -> (Vec2, bool) {
+    camera_query
+        .get_single()
+        .map_or((Vec2::ZERO, false), |camera| {
+            (camera.translation, camera.is_changed())
+        })
+}
+
+/// The components of an entity that change how its [`Transform2d`] is written into its [`Transform`].
+#[derive(WorldQuery)]
+pub struct Transform2dTo3dOptions {
+    has_parent: Has<Parent>,
+    isometric: Has<Isometric>,
+    pixel_snap: Option<Ref<'static, PixelSnap>>,
+    flip: Option<Ref<'static, Flip2d>>,
+}
+
+/// The resources that change how a [`Transform2d`] is written into a [`Transform`].
+#[derive(SystemParam)]
+pub struct Transform2dTo3dParams<'w, 's> {
+    plane: Res<'w, Plane2d>,
+    projection: Res<'w, IsometricProjection>,
+    y_sort_config: Res<'w, YSortConfig>,
+    camera_query: Query<'w, 's, Ref<'static, Transform2d>, With<PixelSnapCamera>>,
+}
+
+impl Transform2dTo3dParams<'_, '_> {
+    /// Returns the [`Transform2dTo3d`] for this run of the system.
+    pub(crate) fn get(&self) -> Transform2dTo3d {
+        let (snap_origin, snap_origin_changed) = pixel_snap_origin(&self.camera_query);
+        Transform2dTo3d {
+            plane: *self.plane,
+            plane_changed: self.plane.is_changed(),
+            projection: *self.projection,
+            y_sort_config: *self.y_sort_config,
+            projection_changed: self.projection.is_changed() || self.y_sort_config.is_changed(),
+            snap_origin,
+            snap_origin_changed,
+        }
+    }
+}
+
+/// Writes a [`Transform2d`] into a [`Transform`].
+///
+/// The [`Transform2d`] of an [`Isometric`] root entity is projected onto the screen, snapped to the [`PixelSnap`] grid,
+/// flipped by the [`Flip2d`] and mapped onto the [`Plane2d`], in that order.
+pub(crate) struct Transform2dTo3d {
+    plane: Plane2d,
+    plane_changed: bool,
+    projection: IsometricProjection,
+    y_sort_config: YSortConfig,
+    projection_changed: bool,
+    snap_origin: Vec2,
+    snap_origin_changed: bool,
+}
+
+impl Transform2dTo3d {
+    /// Returns a [`Transform2dTo3d`] that doesn't snap to the [`PixelSnap`] grid, for mapping a [`Transform`] back into a [`Transform2d`].
+    fn unsnapped(
+        plane: Plane2d,
+        projection: IsometricProjection,
+        y_sort_config: YSortConfig,
+    ) -> Self {
+        Transform2dTo3d {
+            plane,
+            plane_changed: false,
+            projection,
+            y_sort_config,
+            projection_changed: false,
+            snap_origin: Vec2::ZERO,
+            snap_origin_changed: false,
+        }
+    }
+
+    /// Returns whether the [`Transform`] of an entity needs to be written, even if its [`Transform2d`] didn't change.
+    pub(crate) fn is_changed(&self, options: &Transform2dTo3dOptionsItem) -> bool {
+        // Only root entities are mapped onto the `Plane2d`.
+        (self.plane_changed && !options.has_parent)
+            || (self.projection_changed && options.isometric && !options.has_parent)
+            || options.pixel_snap.as_ref().is_some_and(|pixel_snap| {
+                pixel_snap.is_changed() || (self.snap_origin_changed && !options.has_parent)
+            })
+            || options.flip.as_ref().is_some_and(|flip| flip.is_changed())
+    }
+
+    /// Returns the [`Transform`] written for the [`Transform2d`] of an entity.
+    pub(crate) fn transform_3d(
+        &self,
+        transform: Transform2d,
+        options: &Transform2dTo3dOptionsItem,
+    ) -> Transform {
+        self.transform_3d_with_flip(transform, options, options.flip.as_deref())
+    }
+
+    /// Returns the [`Transform`] written for the [`Transform2d`] of an entity with a [`Pivot2d`], a [`Transform2dInheritance`], a [`ZMode`] or a [`YSort`],
+    /// which Bevy's transform propagation turns into the same global transform as its [`GlobalTransform2d`].
+    ///
+    /// `global_z` is the z of the [`GlobalTransform2d`] of the entity and `parent` the [`GlobalTransform2d`] of its parent.
+    pub(crate) fn overridden_transform_3d(
+        &self,
+        transform: Transform2d,
+        global_z: f32,
+        parent: &GlobalTransform2d,
+        options: &Transform2dTo3dOptionsItem,
+        overrides: &PropagationOverrides3dItem,
+    ) -> Transform {
+        let local_z = global_z - parent.z_translation();
+        if overrides.pivot.is_none() && overrides.inheritance.is_none() {
+            return self.transform_3d(transform.with_z_translation(local_z), options);
+        }
+
+        let mut global_transform = overrides
+            .inheritance
+            .map_or(*parent, |inheritance| inheritance.inherit(parent))
+            .mul_transform(transform);
+        if let Some(flip) = &options.flip {
+            global_transform = flip.flip_global(global_transform);
+        }
+        if let Some(pivot) = overrides.pivot {
+            global_transform = pivot.apply(global_transform);
+        }
+        let transform = global_transform
+            .reparented_to(parent)
+            .with_z_translation(local_z);
+        // The flip is part of the global transform already.
+        self.transform_3d_with_flip(transform, options, None)
+    }
+
+    /// Returns the [`Transform2d`] that [`Transform2dTo3d::transform_3d`] turns into `transform`,
+    /// the [`Transform`] of an entity mapped back from the [`Plane2d`].
+    ///
+    /// `current` is the [`Transform2d`] of the entity, which keeps the flipped axis of a [`Flip2d`] apart from a rotation.
+    pub(crate) fn transform_2d(
+        &self,
+        transform: &Transform,
+        current: &Transform2d,
+        options: &Transform2dTo3dOptionsItem,
+    ) -> Transform2d {
+        let transform = match &options.flip {
+            Some(flip) => flip.unflip_transform(transform, current),
+            None => (*transform).into(),
+        };
+        self.unproject(transform, options)
+    }
+
+    /// Returns the [`Transform2d`] that [`Transform2dTo3d::overridden_transform_3d`] turns into `transform`,
+    /// the [`Transform`] of an entity mapped back from the [`Plane2d`].
+    ///
+    /// The [`Pivot2d`] offset and the [`Flip2d`] are removed from the global transform the [`Transform`] propagates into,
+    /// before it's made relative to `parent` again.
+    /// The z that the [`Transform`] was given on top of the z of `current`, eg. by a [`YSort`], is removed as well,
+    /// so only a change of the z of the [`Transform`] is written back.
+    ///
+    /// `global_z` is the z of the [`GlobalTransform2d`] of the entity and `parent` the [`GlobalTransform2d`] of its parent.
+    pub(crate) fn overridden_transform_2d(
+        &self,
+        transform: &Transform,
+        current: &Transform2d,
+        global_z: f32,
+        parent: &GlobalTransform2d,
+        options: &Transform2dTo3dOptionsItem,
+        overrides: &PropagationOverrides3dItem,
+    ) -> Transform2d {
+        let mut transform_2d = if overrides.pivot.is_none() && overrides.inheritance.is_none() {
+            self.transform_2d(transform, current, options)
+        } else {
+            self.unpivoted_transform_2d(transform, parent, options, overrides)
+        };
+        // `overridden_transform_3d` writes this z instead of the z of `current`.
+        let local_z = global_z - parent.z_translation();
+        transform_2d.z_translation += current.z_translation - local_z;
+        transform_2d
+    }
+
+    /// Returns the [`Transform2d`] relative to the inherited parts of `parent` of the global transform that `transform` propagates into,
+    /// with the [`Pivot2d`] offset and the [`Flip2d`] removed.
+    fn unpivoted_transform_2d(
+        &self,
+        transform: &Transform,
+        parent: &GlobalTransform2d,
+        options: &Transform2dTo3dOptionsItem,
+        overrides: &PropagationOverrides3dItem,
+    ) -> Transform2d {
+        let mut global_transform =
+            parent.mul_transform(self.unproject((*transform).into(), options));
+        if let Some(pivot) = overrides.pivot {
+            global_transform = pivot.unapply(global_transform);
+        }
+        if let Some(flip) = &options.flip {
+            // Flipping is its own inverse.
+            global_transform = flip.flip_global(global_transform);
+        }
+        let parent = overrides
+            .inheritance
+            .map_or(*parent, |inheritance| inheritance.inherit(parent));
+        global_transform.reparented_to(&parent)
+    }
+
+    /// Removes the [`IsometricProjection`] from the `transform` of an [`Isometric`] root entity.
+    fn unproject(
+        &self,
+        transform: Transform2d,
+        options: &Transform2dTo3dOptionsItem,
+    ) -> Transform2d {
+        if options.isometric && !options.has_parent {
+            self.projection
+                .unproject_transform(transform, &self.y_sort_config)
+        } else {
+            transform
+        }
+    }
+
+    fn transform_3d_with_flip(
+        &self,
+        mut transform: Transform2d,
+        options: &Transform2dTo3dOptionsItem,
+        flip: Option<&Flip2d>,
+    ) -> Transform {
+        if options.isometric && !options.has_parent {
+            transform = self
+                .projection
+                .project_transform(transform, &self.y_sort_config);
+        }
+        if let Some(pixel_snap) = &options.pixel_snap {
+            // The translation of a child is relative to its parent, which is on the grid already if it's snapped too.
+            let snap_origin = if options.has_parent {
+                Vec2::ZERO
+            } else {
+                self.snap_origin
+            };
+            transform = pixel_snap.snap(transform, snap_origin);
+        }
+        if let Some(flip) = flip {
+            transform = flip.flip(transform);
+        }
+        self.plane
+            .for_entity(options.has_parent)
+            .transform_to_3d(transform.into())
+    }
+}
 
 pub fn sync_transform_2d_to_3d(
-    mut query: Query<(&Transform2d, &mut Transform), Changed<Transform2d>>,
+    params: Transform2dTo3dParams,
+    mut query: Query<(
+        Ref<Transform2d>,
+        Transform2dTo3dOptions,
+        &mut Transform,
+        Option<&mut Transform2dSyncTick>,
+    )>,
+    system_ticks: SystemChangeTick,
 ) {
-    for (&transform_2d, mut transform_3d) in &mut query {
-        *transform_3d = transform_2d.into();
+    let transform_2d_to_3d = params.get();
+    for (transform_2d, options, mut transform_3d, sync_tick) in &mut query {
+        if !transform_2d_to_3d.is_changed(&options)
+            && !is_unsynced(
+                transform_2d.last_changed(),
+                sync_tick.as_deref(),
+                &system_ticks,
+            )
+        {
+            continue;
+        }
+
+        transform_3d.set_if_neq(transform_2d_to_3d.transform_3d(*transform_2d, &options));
+        if let Some(mut sync_tick) = sync_tick {
+            sync_tick.set(system_ticks.this_run());
+        }
     }
 }
 
 pub fn sync_transform_3d_to_2d(
-    mut query: Query<(&mut Transform2d, &Transform), Changed<Transform>>,
+    policy: Res<PlanarProjectionPolicy>,
+    (plane, projection, y_sort_config): (Res<Plane2d>, Res<IsometricProjection>, Res<YSortConfig>),
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform2d,
+            Ref<Transform>,
+            Option<&GlobalTransform2d>,
+            Option<&Parent>,
+            Transform2dTo3dOptions,
+            PropagationOverrides3d,
+            Option<&mut Transform2dSyncTick>,
+        ),
+        Changed<Transform>,
+    >,
+    ancestor_query: AncestorQuery,
+    system_ticks: SystemChangeTick,
+    mut warned: Local<HashSet<Entity>>,
+    mut removed: RemovedComponents<Transform2d>,
+) {
+    for entity in removed.read() {
+        warned.remove(&entity);
+    }
+
+    // Pixel snapping can't be undone, so the `Transform2dTo3dParams` and their query for the `PixelSnapCamera` aren't needed.
+    let transform_2d_to_3d = Transform2dTo3d::unsnapped(*plane, *projection, *y_sort_config);
+    for (
+        entity,
+        mut transform_2d,
+        transform_3d,
+        global_transform,
+        parent,
+        options,
+        overrides,
+        sync_tick,
+    ) in &mut query
+    {
+        if !is_unsynced(
+            transform_3d.last_changed(),
+            sync_tick.as_deref(),
+            &system_ticks,
+        ) {
+            continue;
+        }
+
+        let local_transform = transform_2d_to_3d
+            .plane
+            .for_entity(options.has_parent)
+            .transform_from_3d(*transform_3d);
+        let planar = match Transform2d::try_from_transform(&local_transform) {
+            Ok(_) => true,
+            Err(error) => {
+                match *policy {
+                    PlanarProjectionPolicy::Ignore | PlanarProjectionPolicy::Project => {}
+                    PlanarProjectionPolicy::WarnOnce => {
+                        if warned.insert(entity) {
+                            warn!("the Transform of {entity:?} isn't planar: {error}");
+                        }
+                    }
+                    PlanarProjectionPolicy::PanicInDebug => {
+                        if cfg!(debug_assertions) {
+                            panic!("the Transform of {entity:?} isn't planar: {error}");
+                        }
+                    }
+                }
+                false
+            }
+        };
+
+        let local_transform = if !planar && *policy == PlanarProjectionPolicy::Project {
+            Transform2d::from_transform_projected(&local_transform).into()
+        } else {
+            local_transform
+        };
+        // The `Transform` of entities with overrides was written by `sync_propagation_overrides_to_3d`.
+        let propagated = if overrides.is_empty() {
+            None
+        } else {
+            global_transform.zip(propagated_parent(parent, &ancestor_query))
+        };
+        *transform_2d = match propagated {
+            Some((global_transform, parent)) => transform_2d_to_3d.overridden_transform_2d(
+                &local_transform,
+                &transform_2d,
+                global_transform.z_translation(),
+                &parent,
+                &options,
+                &overrides,
+            ),
+            None => transform_2d_to_3d.transform_2d(&local_transform, &transform_2d, &options),
+        };
+        if let Some(mut sync_tick) = sync_tick {
+            sync_tick.set(system_ticks.this_run());
+        }
+    }
+}
+
+/// Update the [`Transform2d`] component of entities with a [`DTransform2d`].
+///
+/// Entities without a [`Parent`] are made relative to the [`FloatingOrigin2d`].
+pub fn sync_dtransform_2d_to_2d(
+    floating_origin: Res<FloatingOrigin2d>,
+    mut root_query: Query<(Entity, Ref<DTransform2d>, &mut Transform2d), Without<Parent>>,
+    mut child_query: Query<
+        (&DTransform2d, &mut Transform2d),
+        (With<Parent>, Or<(Changed<DTransform2d>, Changed<Parent>)>),
+    >,
+    mut orphaned: RemovedComponents<Parent>,
+    mut orphaned_entities: Local<Vec<Entity>>,
+) {
+    orphaned_entities.clear();
+    orphaned_entities.extend(orphaned.read());
+    orphaned_entities.sort_unstable();
+
+    for (entity, dtransform, mut transform) in &mut root_query {
+        if floating_origin.is_changed()
+            || dtransform.is_changed()
+            || orphaned_entities.binary_search(&entity).is_ok()
+        {
+            *transform = dtransform.to_transform_2d_relative_to(floating_origin.origin);
+        }
+    }
+
+    for (&dtransform, mut transform) in &mut child_query {
+        *transform = dtransform.into();
+    }
+}
+
+/// Returns `translation` relative to the [`FloatingOrigin2d`], for entities without a [`Parent`].
+fn relative_to_floating_origin(
+    translation: Vec2,
+    floating_origin: &FloatingOrigin2d,
+    has_parent: bool,
+) -> Vec2 {
+    if has_parent {
+        translation
+    } else {
+        (translation.as_dvec2() - floating_origin.origin).as_vec2()
+    }
+}
+
+/// Update the [`Transform2d`] component of entities with a [`FixedTransform2d`].
+///
+/// Entities without a [`Parent`] are made relative to the [`FloatingOrigin2d`].
+pub fn sync_fixed_transform_2d_to_2d(
+    floating_origin: Res<FloatingOrigin2d>,
+    mut query: Query<(Ref<FixedTransform2d>, &mut Transform2d, Has<Parent>)>,
+) {
+    for (fixed_transform, mut transform, has_parent) in &mut query {
+        if fixed_transform.is_changed() || (floating_origin.is_changed() && !has_parent) {
+            *transform = fixed_transform.to_transform_2d();
+            transform.translation =
+                relative_to_floating_origin(transform.translation, &floating_origin, has_parent);
+        }
+    }
+}
+
+/// Update the [`Transform2d`] component of entities with a [`GridTransform2d`].
+///
+/// Entities without a [`Parent`] are made relative to the [`FloatingOrigin2d`].
+pub fn sync_grid_transform_2d_to_2d(
+    config: Res<Grid2dConfig>,
+    floating_origin: Res<FloatingOrigin2d>,
+    mut query: Query<(Ref<GridTransform2d>, &mut Transform2d, Has<Parent>)>,
+) {
+    for (grid_transform, mut transform, has_parent) in &mut query {
+        if config.is_changed()
+            || grid_transform.is_changed()
+            || (floating_origin.is_changed() && !has_parent)
+        {
+            grid_transform.write_transform_2d(&config, &mut transform);
+            transform.translation =
+                relative_to_floating_origin(transform.translation, &floating_origin, has_parent);
+        }
+    }
+}
+
+/// Update the `z_translation` of the [`Transform2d`] component of entities with a [`ZOrder`].
+///
+/// This runs after the other systems that write a [`Transform2d`], so the [`ZOrder`] takes precedence over eg. the layer of a [`GridTransform2d`].
+pub fn sync_z_order_to_2d(
+    config: Res<ZOrderConfig>,
+    mut query: Query<(Ref<ZOrder>, &mut Transform2d)>,
+) {
+    for (z_order, mut transform) in &mut query {
+        if config.is_changed() || z_order.is_changed() || transform.is_changed() {
+            let z_translation = z_order.z_translation(&config);
+            if transform.z_translation != z_translation {
+                transform.z_translation = z_translation;
+            }
+        }
+    }
+}
+
+/// Logs a warning for entities whose [`ZOrder`] layer doesn't match the layer of their global z,
+/// because the z of their parents was added to it.
+///
+/// The warning is logged once, until the entity is back on its own layer.
+pub fn warn_z_order_overlap(
+    config: Res<ZOrderConfig>,
+    query: Query<(Entity, &ZOrder, &GlobalTransform2d), Changed<GlobalTransform2d>>,
+    mut warned: Local<HashSet<Entity>>,
+    mut removed: RemovedComponents<ZOrder>,
+) {
+    for entity in removed.read() {
+        warned.remove(&entity);
+    }
+
+    for (entity, z_order, global_transform) in &query {
+        let global_layer = config.layer_at(global_transform.z_translation());
+        if global_layer == z_order.layer {
+            warned.remove(&entity);
+        } else if warned.insert(entity) {
+            warn!(
+                "{entity:?} is on layer {} but is drawn on layer {global_layer}, because the z of its parents is added to it",
+                z_order.layer
+            );
+        }
+    }
+}
+
+/// Logs a warning for entities with a [`Transform`] whose parent is a pure 2D entity.
+///
+/// Pure 2D entities have no [`Transform`], so Bevy's transform propagation stops at them
+/// and the [`GlobalTransform`] of their children is never updated.
+pub fn warn_transform_under_pure_2d_parent(
+    query: Query<(Entity, &Parent), (With<Transform>, Changed<Parent>)>,
+    parent_query: Query<(), (With<GlobalTransform2d>, Without<Transform>)>,
+) {
+    for (entity, parent) in &query {
+        if parent_query.contains(parent.get()) {
+            warn!(
+                "{entity:?} has a Transform but its parent {:?} is a pure 2D entity, so its GlobalTransform won't be updated",
+                parent.get()
+            );
+        }
+    }
+}
+
+/// Logs a warning for entities with both an [`AffineTransform2d`] and a [`Transform`].
+///
+/// The [`AffineTransform2d`] is never written into the [`Transform`], so Bevy's transform propagation uses a stale one.
+/// Children with a [`Transform`] are reported by [`warn_transform_under_pure_2d_parent`].
+pub fn warn_transform_on_affine_entity(
+    query: Query<
+        Entity,
+        (
+            With<AffineTransform2d>,
+            With<Transform>,
+            Or<(Added<AffineTransform2d>, Added<Transform>)>,
+        ),
+    >,
+) {
+    for entity in &query {
+        warn!("{entity:?} has an AffineTransform2d and a Transform, but the AffineTransform2d is never written into the Transform");
+    }
+}
+
+/// Filters entities with a local 2D transform and a [`GlobalTransform2d`], which are reached by [`propagate_transform_2d`] if their parent is.
+type LocalTransform2dFilter = (
+    With<GlobalTransform2d>,
+    Or<(With<Transform2d>, With<AffineTransform2d>)>,
+);
+
+/// Queries the [`GlobalTransform2d`] and [`Parent`] of the ancestors of an entity.
+pub(crate) type AncestorQuery<'w, 's> =
+    Query<'w, 's, (&'static GlobalTransform2d, Option<&'static Parent>), LocalTransform2dFilter>;
+
+/// Returns the [`GlobalTransform2d`] of the `parent` of an entity,
+/// or `None` if [`propagate_transform_2d`] doesn't reach the entity because an ancestor has no 2D transform.
+pub(crate) fn propagated_parent(
+    parent: Option<&Parent>,
+    ancestor_query: &AncestorQuery,
+) -> Option<GlobalTransform2d> {
+    let Some(parent) = parent else {
+        return Some(GlobalTransform2d::IDENTITY);
+    };
+    let (&parent_transform, grandparent) = ancestor_query.get(parent.get()).ok()?;
+    propagated_parent(grandparent, ancestor_query)?;
+    Some(parent_transform)
+}
+
+/// The local 2D transform of an entity, either a [`Transform2d`] or an [`AffineTransform2d`].
+type LocalTransform2d = AnyOf<(Ref<'static, Transform2d>, Ref<'static, AffineTransform2d>)>;
+
+/// The components of an entity that change how its global transform is computed from its parent and local transform.
+type PropagationOverrides2d = (
+    Option<Ref<'static, Flip2d>>,
+    Option<Ref<'static, Pivot2d>>,
+    Option<Ref<'static, Transform2dInheritance>>,
+    Option<Ref<'static, ZMode>>,
+    Option<Ref<'static, YSort>>,
+);
+
+/// Returns the global transform of an entity with the `local` transform, the z its [`YSort`]s added to it, and whether `local` has changed.
+///
+/// The [`AffineTransform2d`] takes precedence if an entity has both.
+/// Only the parts of `parent` selected by the [`Transform2dInheritance`] are used, the [`Flip2d`] and [`Pivot2d`] offset are applied,
+/// the z is made absolute for [`ZMode::Absolute`], and the z of the [`YSort`] is added if the entity has one.
+/// The z that `parent_sort_z` added to the `parent` is replaced by the z of the [`YSort`], so nested sorted entities don't add it twice.
+fn global_transform_2d(
+    parent: &GlobalTransform2d,
+    parent_sort_z: f32,
+    local: (Option<Ref<Transform2d>>, Option<Ref<AffineTransform2d>>),
+    (flip, pivot, inheritance, z_mode, y_sort): (
+        Option<Ref<Flip2d>>,
+        Option<Ref<Pivot2d>>,
+        Option<Ref<Transform2dInheritance>>,
+        Option<Ref<ZMode>>,
+        Option<Ref<YSort>>,
+    ),
+    y_sort_config: &Res<YSortConfig>,
+) -> (GlobalTransform2d, f32, bool) {
+    let mut sort_z = parent_sort_z;
+    let parent = inheritance
+        .as_ref()
+        .map_or(*parent, |inheritance| inheritance.inherit(parent));
+    let (mut global_transform, local_z, mut changed) = match local {
+        (_, Some(affine)) => (
+            parent.mul_affine_transform(*affine),
+            affine.z_translation,
+            affine.is_changed(),
+        ),
+        (Some(transform), None) => (
+            parent.mul_transform(*transform),
+            transform.z_translation,
+            transform.is_changed(),
+        ),
+        (None, None) => unreachable!("`AnyOf` matches at least one component"),
+    };
+    changed |= inheritance.is_some_and(|inheritance| inheritance.is_changed());
+    if let Some(flip) = flip {
+        global_transform = flip.flip_global(global_transform);
+        changed |= flip.is_changed();
+    }
+    if let Some(pivot) = pivot {
+        global_transform = pivot.apply(global_transform);
+        changed |= pivot.is_changed();
+    }
+    if let Some(z_mode) = z_mode {
+        if *z_mode == ZMode::Absolute {
+            global_transform = global_transform.with_z_translation(local_z);
+            sort_z = 0.;
+        }
+        changed |= z_mode.is_changed();
+    }
+    let Some(y_sort) = y_sort else {
+        return (global_transform, sort_z, changed);
+    };
+
+    let z = y_sort_config.z_at(global_transform.translation().y + y_sort.offset);
+    (
+        global_transform.with_z_translation(global_transform.z_translation() - sort_z + z),
+        z,
+        changed || y_sort.is_changed() || y_sort_config.is_changed(),
+    )
+}
+
+/// Update the [`GlobalTransform2d`] component of entities based on the entity hierarchy
+/// and the [`Transform2d`] or [`AffineTransform2d`] component.
+pub fn propagate_transform_2d(
+    y_sort_config: Res<YSortConfig>,
+    mut root_query: Query<
+        (
+            Entity,
+            LocalTransform2d,
+            PropagationOverrides2d,
+            &mut GlobalTransform2d,
+            Option<&Children>,
+        ),
+        Without<Parent>,
+    >,
+    mut transform_query: Query<
+        (
+            LocalTransform2d,
+            PropagationOverrides2d,
+            &mut GlobalTransform2d,
+        ),
+        With<Parent>,
+    >,
+    children_query: Query<&Children>,
+    parent_query: Query<Ref<Parent>>,
+    mut orphaned: RemovedComponents<Parent>,
+    mut orphaned_entities: Local<Vec<Entity>>,
 ) {
-    for (mut transform_2d, &transform_3d) in &mut query {
-        *transform_2d = transform_3d.into();
+    orphaned_entities.clear();
+    orphaned_entities.extend(orphaned.read());
+    orphaned_entities.sort_unstable();
+
+    for (entity, local, global_z, mut global_transform, children) in &mut root_query {
+        let (new_global_transform, sort_z, changed) = global_transform_2d(
+            &GlobalTransform2d::IDENTITY,
+            0.,
+            local,
+            global_z,
+            &y_sort_config,
+        );
+        let changed = changed
+            || global_transform.is_added()
+            || orphaned_entities.binary_search(&entity).is_ok();
+        if changed {
+            *global_transform = new_global_transform;
+        }
+
+        let Some(children) = children else {
+            continue;
+        };
+        for &child in children {
+            propagate_recursive(
+                (*global_transform, sort_z),
+                &y_sort_config,
+                &mut transform_query,
+                &children_query,
+                &parent_query,
+                child,
+                changed,
+            );
+        }
+    }
+}
+
+/// The components that change how [`sync_propagation_overrides_to_3d`] computes the [`Transform`] of an entity.
+#[derive(WorldQuery)]
+pub struct PropagationOverrides3d {
+    pivot: Option<&'static Pivot2d>,
+    inheritance: Option<&'static Transform2dInheritance>,
+    z_mode: Has<ZMode>,
+    y_sort: Has<YSort>,
+}
+
+impl PropagationOverrides3dItem<'_> {
+    /// Returns whether the entity has none of the overrides, so its [`Transform`] is written by [`sync_transform_2d_to_3d`] alone.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pivot.is_none() && self.inheritance.is_none() && !self.z_mode && !self.y_sort
+    }
+}
+
+/// Update the [`Transform`] of entities with a [`Pivot2d`], a [`Transform2dInheritance`], a [`ZMode`] or a [`YSort`],
+/// so Bevy's transform propagation gives them the same global transform as their [`GlobalTransform2d`].
+///
+/// Only the `Z` translation is changed, unless the entity has a [`Pivot2d`] or a [`Transform2dInheritance`].
+/// Pure 2D entities don't need this, their [`GlobalTransform`] is written from their [`GlobalTransform2d`].
+///
+/// Entities with an ancestor that has no 2D transform are skipped, as their [`GlobalTransform2d`] isn't propagated.
+pub fn sync_propagation_overrides_to_3d(
+    params: Transform2dTo3dParams,
+    mut query: Query<
+        (
+            &Transform2d,
+            &GlobalTransform2d,
+            Option<&Parent>,
+            Transform2dTo3dOptions,
+            PropagationOverrides3d,
+            &mut Transform,
+            Option<&mut Transform2dSyncTick>,
+        ),
+        Or<(
+            With<Pivot2d>,
+            With<Transform2dInheritance>,
+            With<ZMode>,
+            With<YSort>,
+        )>,
+    >,
+    ancestor_query: AncestorQuery,
+    system_ticks: SystemChangeTick,
+) {
+    let transform_2d_to_3d = params.get();
+    for (transform_2d, global_transform, parent, options, overrides, mut transform_3d, sync_tick) in
+        &mut query
+    {
+        let Some(parent) = propagated_parent(parent, &ancestor_query) else {
+            continue;
+        };
+        let new_transform_3d = transform_2d_to_3d.overridden_transform_3d(
+            *transform_2d,
+            global_transform.z_translation(),
+            &parent,
+            &options,
+            &overrides,
+        );
+        // Our own write shouldn't be synchronised back into the `Transform2d`.
+        if transform_3d.set_if_neq(new_transform_3d) {
+            if let Some(mut sync_tick) = sync_tick {
+                sync_tick.set(system_ticks.this_run());
+            }
+        }
+    }
+}
+
+/// Update the [`GlobalTransform`] component of pure 2D entities from their [`GlobalTransform2d`].
+///
+/// Pure 2D entities have a [`Transform2d`] or an [`AffineTransform2d`] but no [`Transform`],
+/// so they are skipped by both [`sync_transform_2d_to_3d`] and Bevy's own transform propagation.
+pub fn sync_global_transform_2d_to_3d(
+    plane: Res<Plane2d>,
+    projection: Res<IsometricProjection>,
+    y_sort_config: Res<YSortConfig>,
+    mut query: Query<
+        (Ref<GlobalTransform2d>, &mut GlobalTransform, Has<Isometric>),
+        Without<Transform>,
+    >,
+) {
+    let projection_changed = projection.is_changed() || y_sort_config.is_changed();
+    for (global_transform_2d, mut global_transform, isometric) in &mut query {
+        if global_transform_2d.is_changed()
+            || global_transform.is_added()
+            || plane.is_changed()
+            || (isometric && projection_changed)
+        {
+            let global_transform_2d = if isometric {
+                projection.project_global_transform(*global_transform_2d, &y_sort_config)
+            } else {
+                *global_transform_2d
+            };
+            *global_transform = plane.global_transform_to_3d(global_transform_2d.into());
+        }
+    }
+}
+
+/// `parent` is the global transform of the parent, and the z its [`YSort`]s added to it.
+fn propagate_recursive(
+    (parent, parent_sort_z): (GlobalTransform2d, f32),
+    y_sort_config: &Res<YSortConfig>,
+    transform_query: &mut Query<
+        (
+            LocalTransform2d,
+            PropagationOverrides2d,
+            &mut GlobalTransform2d,
+        ),
+        With<Parent>,
+    >,
+    children_query: &Query<&Children>,
+    parent_query: &Query<Ref<Parent>>,
+    entity: Entity,
+    mut changed: bool,
+) {
+    let Ok((local, global_z, mut global_transform)) = transform_query.get_mut(entity) else {
+        return;
+    };
+
+    let (new_global_transform, sort_z, local_changed) =
+        global_transform_2d(&parent, parent_sort_z, local, global_z, y_sort_config);
+    changed |= local_changed
+        || global_transform.is_added()
+        || parent_query
+            .get(entity)
+            .is_ok_and(|parent| parent.is_changed());
+    if changed {
+        *global_transform = new_global_transform;
+    }
+    let global_transform = *global_transform;
+
+    let Ok(children) = children_query.get(entity) else {
+        return;
+    };
+    for &child in children {
+        propagate_recursive(
+            (global_transform, sort_z),
+            y_sort_config,
+            transform_query,
+            children_query,
+            parent_query,
+            child,
+            changed,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::schedule::{IntoSystemConfigs, Schedule};
+
+    use super::*;
+
+    /// Creates a [`World`] with the resources of the [`Transform2dPlugin`](crate::Transform2dPlugin),
+    /// and a [`Schedule`] that runs `systems`.
+    fn setup<M>(systems: impl IntoSystemConfigs<M>) -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<PlanarProjectionPolicy>();
+        world.init_resource::<Plane2d>();
+        world.init_resource::<IsometricProjection>();
+        world.init_resource::<YSortConfig>();
+        world.init_resource::<ZOrderConfig>();
+        world.init_resource::<Grid2dConfig>();
+        world.init_resource::<FloatingOrigin2d>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(systems);
+        (world, schedule)
+    }
+
+    #[test]
+    fn sync_does_not_feed_back() {
+        let (mut world, mut schedule) = setup(
+            (
+                insert_sync_ticks,
+                apply_deferred,
+                sync_transform_2d_to_3d,
+                sync_transform_3d_to_2d,
+            )
+                .chain(),
+        );
+
+        let entity = world
+            .spawn((Transform2d::from_xy(1., 2.), Transform::default()))
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::new(1., 2., 0.)
+        );
+
+        // Neither side changed, so neither side should be written to.
+        world.clear_trackers();
+        let transform_2d_tick = world
+            .entity(entity)
+            .get_change_ticks::<Transform2d>()
+            .unwrap()
+            .last_changed_tick();
+        let transform_3d_tick = world
+            .entity(entity)
+            .get_change_ticks::<Transform>()
+            .unwrap()
+            .last_changed_tick();
+        schedule.run(&mut world);
+        let ticks = world.entity(entity);
+        assert_eq!(
+            ticks
+                .get_change_ticks::<Transform2d>()
+                .unwrap()
+                .last_changed_tick(),
+            transform_2d_tick
+        );
+        assert_eq!(
+            ticks
+                .get_change_ticks::<Transform>()
+                .unwrap()
+                .last_changed_tick(),
+            transform_3d_tick
+        );
+
+        // A change to the 3D side is written to the 2D side once.
+        world.get_mut::<Transform>(entity).unwrap().translation.x = 5.;
+        world.clear_trackers();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Transform2d>(entity).unwrap().translation.x, 5.);
+    }
+
+    #[test]
+    fn propagate_mixed_hierarchy() {
+        let (mut world, mut schedule) =
+            setup((propagate_transform_2d, sync_global_transform_2d_to_3d).chain());
+
+        let parent_transform = Transform2d::from_xyz(10., 0., 1.).with_rotation(1.);
+        let child_transform = AffineTransform2d::from_shear(0.5, 0.).with_z_translation(2.);
+        let parent = world
+            .spawn((parent_transform, GlobalTransform2d::default()))
+            .id();
+        let child = world
+            .spawn((
+                child_transform,
+                GlobalTransform2d::default(),
+                GlobalTransform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+
+        schedule.run(&mut world);
+
+        let expected =
+            GlobalTransform2d::from(parent_transform).mul_affine_transform(child_transform);
+        assert_eq!(*world.get::<GlobalTransform2d>(child).unwrap(), expected);
+        assert_eq!(expected.z_translation(), 3.);
+        assert_eq!(
+            *world.get::<GlobalTransform>(child).unwrap(),
+            GlobalTransform::from(expected)
+        );
+    }
+
+    #[test]
+    fn absolute_z() {
+        let (mut world, mut schedule) = setup(
+            (
+                sync_transform_2d_to_3d,
+                propagate_transform_2d,
+                sync_propagation_overrides_to_3d,
+                bevy::transform::systems::propagate_transforms,
+            )
+                .chain(),
+        );
+
+        let parent = world
+            .spawn((
+                Transform2d::from_xyz(10., 0., 5.),
+                GlobalTransform2d::default(),
+                Transform::default(),
+                GlobalTransform::default(),
+            ))
+            .id();
+        let child = world
+            .spawn((
+                Transform2d::from_xyz(0., 2., 1.),
+                ZMode::Absolute,
+                GlobalTransform2d::default(),
+                Transform::default(),
+                GlobalTransform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+
+        schedule.run(&mut world);
+
+        let global_transform = world.get::<GlobalTransform2d>(child).unwrap();
+        assert_eq!(global_transform.translation(), Vec2::new(10., 2.));
+        assert_eq!(global_transform.z_translation(), 1.);
+        // Bevy's propagation gives the child the same global transform.
+        assert_eq!(
+            world.get::<GlobalTransform>(child).unwrap().translation(),
+            Vec3::new(10., 2., 1.)
+        );
+    }
+
+    #[test]
+    fn nested_y_sort() {
+        let (mut world, mut schedule) = setup(propagate_transform_2d);
+
+        let parent = world
+            .spawn((
+                Transform2d::from_xyz(0., 0., 1.),
+                YSort::default(),
+                GlobalTransform2d::default(),
+            ))
+            .id();
+        let child = world
+            .spawn((
+                Transform2d::from_xyz(0., 100., 0.5),
+                YSort::default(),
+                GlobalTransform2d::default(),
+            ))
+            .set_parent(parent)
+            .id();
+        let grandchild = world
+            .spawn((
+                Transform2d::from_xyz(0., 0., 0.25),
+                GlobalTransform2d::default(),
+            ))
+            .set_parent(child)
+            .id();
+
+        schedule.run(&mut world);
+
+        let config = *world.resource::<YSortConfig>();
+        let parent_z = world
+            .get::<GlobalTransform2d>(parent)
+            .unwrap()
+            .z_translation();
+        let child_z = world
+            .get::<GlobalTransform2d>(child)
+            .unwrap()
+            .z_translation();
+        let grandchild_z = world
+            .get::<GlobalTransform2d>(grandchild)
+            .unwrap()
+            .z_translation();
+        assert_eq!(parent_z, 1. + config.z_at(0.));
+        // The child is sorted by its own `Y` only, not by the `Y` of its parent as well.
+        assert_eq!(child_z, 1.5 + config.z_at(100.));
+        // Children without a `YSort` are drawn along with their sorted parent.
+        assert_eq!(grandchild_z, child_z + 0.25);
+    }
+
+    #[test]
+    fn overrides_skip_3d_parents() {
+        let (mut world, mut schedule) = setup(
+            (
+                sync_transform_2d_to_3d,
+                propagate_transform_2d,
+                sync_propagation_overrides_to_3d,
+            )
+                .chain(),
+        );
+
+        let parent = world
+            .spawn((Transform::default(), GlobalTransform::default()))
+            .id();
+        let y_sorted = world
+            .spawn((
+                Transform2d::from_xyz(5., 7., 3.),
+                YSort::default(),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+        let pivoted = world
+            .spawn((
+                Transform2d::from_xyz(5., 7., 3.),
+                Pivot2d(Vec2::ONE),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+
+        schedule.run(&mut world);
+
+        // The `GlobalTransform2d` of these isn't propagated, so their `Transform` is left as it was synced.
+        assert_eq!(
+            world.get::<Transform>(y_sorted).unwrap().translation,
+            Vec3::new(5., 7., 3.)
+        );
+        assert_eq!(
+            world.get::<Transform>(pivoted).unwrap().translation,
+            Vec3::new(5., 7., 3.)
+        );
+    }
+
+    /// Like [`setup`], with the systems that write a [`Transform2d`] into a [`Transform`] and back,
+    /// in the order they run in when a physics engine writes the [`Transform`].
+    fn setup_write_back() -> (World, Schedule) {
+        setup(
+            (
+                insert_sync_ticks,
+                apply_deferred,
+                sync_transform_2d_to_3d,
+                sync_transform_3d_to_2d,
+                propagate_transform_2d,
+                sync_propagation_overrides_to_3d,
+            )
+                .chain(),
+        )
+    }
+
+    /// Marks the [`Transform`] of `entity` as changed a few times, as if it was written back unchanged.
+    fn write_back_unchanged(world: &mut World, schedule: &mut Schedule, entity: Entity) {
+        for _ in 0..3 {
+            world.get_mut::<Transform>(entity).unwrap().set_changed();
+            schedule.run(world);
+        }
+    }
+
+    #[test]
+    fn pivot_write_back() {
+        let (mut world, mut schedule) = setup_write_back();
+
+        let parent = world
+            .spawn((
+                Transform2d::from_xy(5., 0.).with_rotation(1.),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .id();
+        let entity = world
+            .spawn((
+                Transform2d::from_rotation(0.5),
+                Pivot2d(Vec2::new(10., 0.)),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+        schedule.run(&mut world);
+
+        // Writing the `Transform` back unchanged, eg. from a physics engine, doesn't move the entity.
+        write_back_unchanged(&mut world, &mut schedule, entity);
+        let transform_2d = world.get::<Transform2d>(entity).unwrap();
+        assert!(transform_2d.translation.abs_diff_eq(Vec2::ZERO, 1e-5));
+        assert!((transform_2d.rotation - 0.5).abs() < 1e-5);
+
+        // Moving the `Transform` moves the `Transform2d` by as much.
+        world.get_mut::<Transform>(entity).unwrap().translation += Vec3::Y * 5.;
+        schedule.run(&mut world);
+        let transform_2d = world.get::<Transform2d>(entity).unwrap();
+        assert!(transform_2d
+            .translation
+            .abs_diff_eq(Vec2::new(0., 5.), 1e-5));
+        assert!((transform_2d.rotation - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn y_sort_write_back() {
+        let (mut world, mut schedule) = setup_write_back();
+
+        let entity = world
+            .spawn((
+                Transform2d::from_xyz(0., 10., 1.),
+                YSort::default(),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .id();
+        schedule.run(&mut world);
+        let sorted_z = 1. + world.resource::<YSortConfig>().z_at(10.);
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation.z,
+            sorted_z
+        );
+
+        // The z of the `YSort` isn't added to the `Transform2d`.
+        write_back_unchanged(&mut world, &mut schedule, entity);
+        assert_eq!(world.get::<Transform2d>(entity).unwrap().z_translation, 1.);
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation.z,
+            sorted_z
+        );
+
+        // A change of the z of the `Transform` is.
+        world.get_mut::<Transform>(entity).unwrap().translation.z += 0.5;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Transform2d>(entity).unwrap().z_translation, 1.5);
+    }
+
+    #[test]
+    fn absolute_z_write_back() {
+        let (mut world, mut schedule) = setup_write_back();
+
+        let parent = world
+            .spawn((
+                Transform2d::from_xyz(0., 0., 5.),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .id();
+        let entity = world
+            .spawn((
+                Transform2d::from_xyz(0., 0., 1.),
+                ZMode::Absolute,
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+        schedule.run(&mut world);
+        // The `Transform` is relative to the parent, which Bevy's transform propagation adds the z of.
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation.z, -4.);
+
+        // The absolute z is written back, not the z relative to the parent.
+        write_back_unchanged(&mut world, &mut schedule, entity);
+        assert_eq!(world.get::<Transform2d>(entity).unwrap().z_translation, 1.);
+        assert_eq!(
+            world
+                .get::<GlobalTransform2d>(entity)
+                .unwrap()
+                .z_translation(),
+            1.
+        );
+
+        world.get_mut::<Transform>(entity).unwrap().translation.z = -3.;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Transform2d>(entity).unwrap().z_translation, 2.);
+    }
+
+    #[test]
+    fn inheritance_write_back() {
+        let (mut world, mut schedule) = setup_write_back();
+
+        let parent = world
+            .spawn((
+                Transform2d::from_xy(5., 0.).with_rotation(1.),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .id();
+        let entity = world
+            .spawn((
+                Transform2d::from_xy(0., 10.).with_rotation(0.5),
+                Transform2dInheritance::TRANSLATION_ONLY,
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+        schedule.run(&mut world);
+
+        // The rotation of the parent isn't subtracted from the entity.
+        write_back_unchanged(&mut world, &mut schedule, entity);
+        let transform_2d = world.get::<Transform2d>(entity).unwrap();
+        assert!(transform_2d
+            .translation
+            .abs_diff_eq(Vec2::new(0., 10.), 1e-4));
+        assert!((transform_2d.rotation - 0.5).abs() < 1e-5);
+        assert!((world.get::<GlobalTransform2d>(entity).unwrap().rotation() - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn resync_on_plane_change() {
+        let (mut world, mut schedule) =
+            setup((insert_sync_ticks, apply_deferred, sync_transform_2d_to_3d).chain());
+
+        let entity = world
+            .spawn((Transform2d::from_xyz(1., 2., 3.), Transform::default()))
+            .id();
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::new(1., 2., 3.)
+        );
+
+        world.insert_resource(Plane2d::XZ);
+        schedule.run(&mut world);
+        assert!(world
+            .get::<Transform>(entity)
+            .unwrap()
+            .translation
+            .abs_diff_eq(Vec3::new(1., 3., -2.), 1e-6));
+    }
+
+    #[test]
+    fn isometric_round_trip() {
+        let (mut world, mut schedule) = setup(
+            (
+                insert_sync_ticks,
+                apply_deferred,
+                sync_transform_2d_to_3d,
+                sync_transform_3d_to_2d,
+            )
+                .chain(),
+        );
+
+        let entity = world
+            .spawn((
+                Transform2d::from_xyz(1., 0., 2.),
+                Isometric,
+                Transform::default(),
+            ))
+            .id();
+        schedule.run(&mut world);
+
+        // The projection is resynchronised when it changes.
+        let projection = IsometricProjection::isometric(2.);
+        world.insert_resource(projection);
+        schedule.run(&mut world);
+        let screen = projection.project(Vec2::new(1., 0.));
+        assert_eq!(
+            world
+                .get::<Transform>(entity)
+                .unwrap()
+                .translation
+                .truncate(),
+            screen
+        );
+
+        // A `Transform` written by eg. a physics engine is projected back onto the ground.
+        let screen = projection.project(Vec2::new(4., 1.));
+        let z = world.resource::<YSortConfig>().z_at(screen.y) + 2.;
+        world.get_mut::<Transform>(entity).unwrap().translation = screen.extend(z);
+        schedule.run(&mut world);
+        let transform_2d = world.get::<Transform2d>(entity).unwrap();
+        assert!(transform_2d
+            .translation
+            .abs_diff_eq(Vec2::new(4., 1.), 1e-5));
+        assert!((transform_2d.z_translation - 2.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn project_flipped() {
+        let (mut world, mut schedule) = setup(
+            (
+                insert_sync_ticks,
+                apply_deferred,
+                sync_transform_2d_to_3d,
+                sync_transform_3d_to_2d,
+            )
+                .chain(),
+        );
+        world.insert_resource(PlanarProjectionPolicy::Project);
+
+        let entity = world
+            .spawn((
+                Transform2d::from_rotation(1.),
+                Flip2d::X,
+                Transform::default(),
+            ))
+            .id();
+        schedule.run(&mut world);
+
+        world.get_mut::<Transform>(entity).unwrap().rotation =
+            Quat::from_rotation_z(1.) * Quat::from_rotation_x(0.5);
+        schedule.run(&mut world);
+
+        let transform_2d = world.get::<Transform2d>(entity).unwrap();
+        assert!((transform_2d.rotation - 1.).abs() < 1e-5);
+        assert!(transform_2d.scale.abs_diff_eq(Vec2::ONE, 1e-5));
+    }
+
+    #[test]
+    fn pixel_snap_children() {
+        let (mut world, mut schedule) = setup(sync_transform_2d_to_3d);
+
+        world.spawn((Transform2d::from_xy(0.3, 0.), PixelSnapCamera));
+        let parent = world
+            .spawn((
+                Transform2d::from_xy(100.4, 0.),
+                PixelSnap::new(1.),
+                Transform::default(),
+            ))
+            .id();
+        let child = world
+            .spawn((
+                Transform2d::from_xy(1.2, 0.),
+                PixelSnap::new(1.),
+                Transform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+        schedule.run(&mut world);
+
+        let parent_x = world.get::<Transform>(parent).unwrap().translation.x;
+        let child_x = world.get::<Transform>(child).unwrap().translation.x;
+        assert!((parent_x - 100.3).abs() < 1e-4);
+        // The child is snapped relative to its parent, so it's on the grid of the camera too.
+        assert_eq!(child_x, 1.);
+    }
+
+    #[test]
+    fn z_order_over_grid_layer() {
+        let (mut world, mut schedule) =
+            setup((sync_grid_transform_2d_to_2d, sync_z_order_to_2d).chain());
+
+        let entity = world
+            .spawn((
+                Transform2d::default(),
+                GridTransform2d::from_xy(1, 2).with_layer(4),
+                ZOrder::from_layer(1),
+            ))
+            .id();
+        schedule.run(&mut world);
+        let z_translation = ZOrder::from_layer(1).z_translation(world.resource::<ZOrderConfig>());
+        assert_eq!(
+            world.get::<Transform2d>(entity).unwrap().z_translation,
+            z_translation
+        );
+
+        // Moving on the grid doesn't reset the z to the grid layer.
+        world.get_mut::<GridTransform2d>(entity).unwrap().cell.x = 3;
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Transform2d>(entity).unwrap().z_translation,
+            z_translation
+        );
+    }
+
+    #[test]
+    fn overrides_keep_isometric_projection() {
+        let (mut world, mut schedule) = setup(
+            (
+                sync_transform_2d_to_3d,
+                propagate_transform_2d,
+                sync_propagation_overrides_to_3d,
+            )
+                .chain(),
+        );
+
+        let plain = world
+            .spawn((
+                Transform2d::from_xyz(3., 1., 2.),
+                Isometric,
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .id();
+        let pivoted = world
+            .spawn((
+                Transform2d::from_xyz(3., 1., 2.),
+                Isometric,
+                Pivot2d(Vec2::ZERO),
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .id();
+        let absolute = world
+            .spawn((
+                Transform2d::from_xyz(3., 1., 2.),
+                Isometric,
+                ZMode::Absolute,
+                GlobalTransform2d::default(),
+                Transform::default(),
+            ))
+            .id();
+
+        schedule.run(&mut world);
+
+        let expected = *world.get::<Transform>(plain).unwrap();
+        let projection = *world.resource::<IsometricProjection>();
+        assert_eq!(
+            expected.translation.truncate(),
+            projection.project(Vec2::new(3., 1.))
+        );
+        assert!(expected.translation.z > 2.);
+        for entity in [pivoted, absolute] {
+            let transform = world.get::<Transform>(entity).unwrap();
+            assert!(transform
+                .translation
+                .abs_diff_eq(expected.translation, 1e-6));
+        }
     }
 }
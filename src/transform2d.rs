@@ -1,17 +1,36 @@
+use std::{
+    error::Error,
+    f32::consts::{PI, TAU},
+    fmt,
+    ops::{Mul, MulAssign},
+};
+
 use bevy::{math::Affine2, prelude::*};
 
+use crate::rot2::{IntoRotation, Rot2};
+
 /// Describes the position of an [`Entity`] in 2d space.
 ///
-/// This component acts as a proxy to the [`Transform`] component,
-/// and thus *requires* that both a [`Transform`] and [`GlobalTransform`] are present to function.
+/// This component acts as a proxy to the [`Transform`] component if the entity has one,
+/// and *requires* that a [`GlobalTransform`] is present to function.
+///
+/// Entities without a [`Transform`] are treated as pure 2D entities,
+/// their [`GlobalTransform`] is written directly from their [`GlobalTransform2d`](crate::global_transform2d::GlobalTransform2d) instead.
+/// See [`Pure2dBundle`](crate::bundle::Pure2dBundle).
 ///
 /// If this [`Transform2d`] has a [`Parent`], then it's relative to the [`Transform2d`] or [`Transform`] of the [`Parent`].
 #[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct Transform2d {
     /// The translation along the `X` and `Y` axes.
     pub translation: Vec2,
     /// The rotation in radians. Positive values rotate anti-clockwise.
+    ///
+    /// This stays an angle rather than a [`Rot2`], so it can be edited, animated and reflected as a single number.
+    /// The price is that [`local_x()`][Self::local_x], [`local_y()`][Self::local_y] and [`rotation_matrix()`][Self::rotation_matrix]
+    /// each compute its sine and cosine. Use [`rot2()`][Self::rot2] when you need more than one of them.
     pub rotation: f32,
     /// The scale along the `X` and `Y` axes.
     pub scale: Vec2,
@@ -21,7 +40,8 @@ pub struct Transform2d {
     /// but this third dimension is used when rendering to decide what should appear in front or behind.
     /// A higher translation on the Z axis puts the entity closer to the camera, and thus in front of entities with a lower Z translation.
     ///
-    /// Keep in mind that this is relative to the [`Parent`]'s `z_translation`.
+    /// Keep in mind that this is relative to the [`Parent`]'s `z_translation`,
+    /// unless the entity has [`ZMode::Absolute`](crate::z_order::ZMode::Absolute).
     /// The other fields on [`Transform2d`] don't affect this because they are strictly 2D.
     pub z_translation: f32,
 }
@@ -32,6 +52,39 @@ impl Default for Transform2d {
     }
 }
 
+/// Decomposes an [`Affine2`] or a [`DAffine2`](bevy::math::DAffine2) of `$float`s into a `Result` with its translation, rotation and scale,
+/// for [`Transform2d::try_from_affine`] and [`DTransform2d::try_from_affine`](crate::dtransform2d::DTransform2d::try_from_affine).
+///
+/// Mirroring is represented by a negative `X` scale.
+/// Returns an error if the affine transform is degenerate or contains shear, within the [`AFFINE_EPSILON`] tolerance.
+macro_rules! decompose_affine {
+    ($affine:expr, $float:ty) => {{
+        let affine = $affine;
+        let (x_axis, y_axis) = (affine.matrix2.x_axis, affine.matrix2.y_axis);
+
+        let determinant = x_axis.perp_dot(y_axis);
+        let (x_length, y_length) = (x_axis.length(), y_axis.length());
+        let shear = x_axis.dot(y_axis) / (x_length * y_length);
+        if determinant.abs() <= <$float>::EPSILON * x_length * y_length || !determinant.is_finite()
+        {
+            Err($crate::transform2d::Transform2dFromAffineError::Degenerate)
+        } else if shear.abs() > $crate::transform2d::AFFINE_EPSILON as $float {
+            Err($crate::transform2d::Transform2dFromAffineError::Shear {
+                shear: shear as f32,
+            })
+        } else {
+            // The direction of the local `X` axis after removing any mirroring.
+            let local_x = x_axis / x_length * determinant.signum();
+            Ok((
+                affine.translation,
+                local_x.y.atan2(local_x.x),
+                (x_length * determinant.signum(), local_x.perp().dot(y_axis)).into(),
+            ))
+        }
+    }};
+}
+pub(crate) use decompose_affine;
+
 impl Transform2d {
     /// Creates a new identity [`Transform2d`], with no translation, rotation, and a scale of 1 on all axes.
     ///
@@ -86,9 +139,9 @@ impl Transform2d {
     ///
     /// Translation will be `Vec2::ZERO`, scale will be `Vec2::ONE` and `z_translation` will be `0.`.
     #[inline]
-    pub fn from_rotation(rotation: f32) -> Self {
+    pub fn from_rotation(rotation: impl IntoRotation) -> Self {
         Transform2d {
-            rotation,
+            rotation: rotation.into_rotation(),
             ..Self::IDENTITY
         }
     }
@@ -115,8 +168,8 @@ impl Transform2d {
     /// Returns this [`Transform2d`] with a new rotation.
     #[must_use]
     #[inline]
-    pub fn with_rotation(mut self, rotation: f32) -> Self {
-        self.rotation = rotation;
+    pub fn with_rotation(mut self, rotation: impl IntoRotation) -> Self {
+        self.rotation = rotation.into_rotation();
         self
     }
 
@@ -192,7 +245,7 @@ impl Transform2d {
     /// If this [`Transform2d`] has a parent, the `point` is relative to the [`Transform2d`] of the parent.
     #[inline]
     pub fn point_to(&mut self, direction: Vec2, target_direction: Vec2) {
-        self.rotation = Vec2::angle_between(direction, target_direction);
+        self.rotation = Rot2::from_to(direction, target_direction).as_radians();
     }
 
     /// Rotates this [`Transform2d`] so the local `direction` points at the given `target_position`.
@@ -215,11 +268,21 @@ impl Transform2d {
         self.point_to(direction, target_position - self.translation);
     }
 
+    /// Returns the rotation of this [`Transform2d`] as a [`Rot2`].
+    ///
+    /// This computes the sine and cosine of the rotation once, so get the [`Rot2`] when you need several of
+    /// [`local_x()`][Self::local_x], [`local_y()`][Self::local_y] and [`rotation_matrix()`][Self::rotation_matrix].
+    #[inline]
+    pub fn rot2(&self) -> Rot2 {
+        Rot2::radians(self.rotation)
+    }
+
     /// Get the unit vector in the local `X` direction.
+    ///
+    /// This computes the sine and cosine of the rotation, see [`rot2()`][Self::rot2].
     #[inline]
     pub fn local_x(&self) -> Vec2 {
-        let (sin, cos) = self.rotation.sin_cos();
-        (cos, sin).into()
+        self.rot2().local_x()
     }
 
     #[inline]
@@ -235,10 +298,11 @@ impl Transform2d {
     }
 
     /// Get the unit vector in the local `Y` direction.
+    ///
+    /// This computes the sine and cosine of the rotation, see [`rot2()`][Self::rot2].
     #[inline]
     pub fn local_y(&self) -> Vec2 {
-        let (sin, cos) = self.rotation.sin_cos();
-        (-sin, cos).into()
+        self.rot2().local_y()
     }
 
     /// Equivalent to [`local_y()`][Self::local_y]
@@ -254,9 +318,11 @@ impl Transform2d {
     }
 
     /// Returns the rotation matrix from this transforms rotation.
+    ///
+    /// This computes the sine and cosine of the rotation, see [`rot2()`][Self::rot2].
     #[inline]
     pub fn rotation_matrix(&self) -> Mat2 {
-        Mat2::from_angle(self.rotation)
+        self.rot2().to_mat2()
     }
 
     /// Computes the affine transformation matrix of this transform.
@@ -271,19 +337,101 @@ impl Transform2d {
         Affine2::from_scale_angle_translation(self.scale, self.rotation, self.translation)
     }
 
+    /// Computes the inverse of the affine transform of this transform.
+    ///
+    /// Unlike [`try_inverse()`][Self::try_inverse] this works for any non-zero scale.
+    #[inline]
+    pub fn compute_inverse_affine(&self) -> Affine2 {
+        let matrix2 = Mat2::from_diagonal(self.scale.recip()) * Mat2::from_angle(-self.rotation);
+        Affine2 {
+            matrix2,
+            translation: -(matrix2 * self.translation),
+        }
+    }
+
+    /// Decomposes `affine` into translation, rotation and scale.
+    ///
+    /// Mirroring is represented by a negative `scale.x`, and `z_translation` will be `0.`.
+    ///
+    /// Returns an error if `affine` is degenerate or contains shear, which a [`Transform2d`] can't represent.
+    /// Shear within a small tolerance, such as from rounding errors, is discarded.
+    pub fn try_from_affine(affine: Affine2) -> Result<Self, Transform2dFromAffineError> {
+        let (translation, rotation, scale) = decompose_affine!(affine, f32)?;
+        Ok(Transform2d {
+            translation,
+            rotation,
+            scale,
+            z_translation: 0.,
+        })
+    }
+
+    /// Converts `transform` into a [`Transform2d`], if it lies in the `XY` plane.
+    ///
+    /// Returns an error with the angle between the local `Z` axis of `transform` and the global `Z` axis
+    /// if it's rotated out of the `XY` plane, which a [`Transform2d`] can't represent.
+    /// A tilt within a small tolerance, such as from rounding errors, is discarded.
+    /// Returns an error as well if the `Z` scale of `transform` isn't 1, as a [`Transform2d`] has no `Z` scale.
+    pub fn try_from_transform(
+        transform: &Transform,
+    ) -> Result<Self, Transform2dFromTransformError> {
+        let tilt = (transform.rotation * Vec3::Z).angle_between(Vec3::Z);
+        if tilt > AFFINE_EPSILON || tilt.is_nan() {
+            return Err(Transform2dFromTransformError::NonPlanar { tilt });
+        }
+        if (transform.scale.z - 1.).abs() > AFFINE_EPSILON {
+            return Err(Transform2dFromTransformError::ScaleZ {
+                scale_z: transform.scale.z,
+            });
+        }
+        Ok((*transform).into())
+    }
+
+    /// Converts `transform` into a [`Transform2d`] by projecting its rotation onto the `XY` plane.
+    ///
+    /// Unlike the [`From`] implementation, this keeps the direction the local `X` axis points in
+    /// when `transform` is rotated out of the `XY` plane. The scale along the `X` and `Y` axes is kept as is.
+    pub fn from_transform_projected(transform: &Transform) -> Self {
+        let x_axis = (transform.rotation * Vec3::X).truncate();
+        let rotation = match x_axis.try_normalize() {
+            Some(x_axis) => x_axis.y.atan2(x_axis.x),
+            // The local `X` axis points along `Z`, so fall back to the local `Y` axis.
+            None => {
+                let y_axis = (transform.rotation * Vec3::Y).truncate();
+                (-y_axis.x).atan2(y_axis.y)
+            }
+        };
+        Transform2d {
+            translation: transform.translation.truncate(),
+            rotation,
+            scale: transform.scale.truncate(),
+            z_translation: transform.translation.z,
+        }
+    }
+
     /// Translates this [`Transform2d`] around a `point` in space.
     ///
     /// If this [`Transform2d`] has a parent, the `point` is relative to the [`Transform2d`] or [`Transform`] of the parent.
     #[inline]
-    pub fn translate_around(&mut self, point: Vec2, angle: f32) {
-        self.translation = point + Mat2::from_angle(angle) * (self.translation - point);
+    pub fn translate_around(&mut self, point: Vec2, angle: impl IntoRotation) {
+        self.translation = point + angle.into_rot2() * (self.translation - point);
+    }
+
+    /// Rotates this [`Transform2d`] by `angle`.
+    ///
+    /// The resulting rotation is wrapped into the range `(-PI, PI]`, so rotating every frame won't make it grow without bound.
+    #[inline]
+    pub fn rotate(&mut self, angle: impl IntoRotation) {
+        self.rotation = PI - (PI - (self.rotation + angle.into_rotation())).rem_euclid(TAU);
     }
 
     /// Rotates this [`Transform2d`] around a `point` in space.
     ///
+    /// Unlike [`rotate()`][Self::rotate] the resulting rotation is not wrapped.
+    ///
     /// If this [`Transform2d`] has a parent, the `point` is relative to the [`Transform2d`] or [`Transform`] of the parent.
     #[inline]
-    pub fn rotate_around(&mut self, point: Vec2, angle: f32) {
+    pub fn rotate_around(&mut self, point: Vec2, angle: impl IntoRotation) {
+        let angle = angle.into_rotation();
         self.translate_around(point, angle);
         self.rotation += angle;
     }
@@ -298,7 +446,7 @@ impl Transform2d {
     /// that is in global space into one relative to this [`Transform2d`].
     ///
     /// If you want to transform a `point` in global space to the local space of this [`Transform2d`],
-    /// consider using [`GlobalTransform2d::transform_point()`](super::GlobalTransform2d::transform_point) instead.
+    /// consider using [`GlobalTransform2d::inverse_transform_point()`](crate::global_transform2d::GlobalTransform2d::inverse_transform_point) instead.
     #[inline]
     pub fn transform_point(&self, mut point: Vec2) -> Vec2 {
         point *= self.scale;
@@ -307,6 +455,47 @@ impl Transform2d {
         point
     }
 
+    /// Transforms the given `point` by the inverse of this [`Transform2d`],
+    /// undoing translation, rotation and scale.
+    /// `z_translation` is ignored.
+    ///
+    /// This is the inverse of [`transform_point()`][Self::transform_point],
+    /// and turns a `point` relative to the parent into one relative to this [`Transform2d`].
+    #[inline]
+    pub fn inverse_transform_point(&self, mut point: Vec2) -> Vec2 {
+        point -= self.translation;
+        point = self.rotation_matrix().transpose() * point;
+        point /= self.scale;
+        point
+    }
+
+    /// Transforms the given `vector`, applying scale and rotation.
+    /// Translation and `z_translation` are ignored.
+    #[inline]
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        self.rotation_matrix() * (vector * self.scale)
+    }
+
+    /// Transforms the given `vector` by the inverse of this [`Transform2d`],
+    /// undoing rotation and scale.
+    /// Translation and `z_translation` are ignored.
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: Vec2) -> Vec2 {
+        (self.rotation_matrix().transpose() * vector) / self.scale
+    }
+
+    /// Returns the inverse of this [`Transform2d`], including `z_translation`.
+    ///
+    /// The inverse of a non-uniform scale followed by a rotation contains shear, unless the rotation is a multiple of 90 degrees.
+    /// A [`Transform2d`] can't represent that, so an error is returned instead, as well as when the scale is zero along an axis.
+    /// Use [`inverse_transform_point()`][Self::inverse_transform_point] or
+    /// [`compute_inverse_affine()`][Self::compute_inverse_affine], which are exact for any non-zero scale, in that case.
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Self, Transform2dFromAffineError> {
+        Transform2d::try_from_affine(self.compute_inverse_affine())
+            .map(|inverse| inverse.with_z_translation(-self.z_translation))
+    }
+
     /// Multiplies `self` with `transform` component by component, returning the
     /// resulting [`Transform2d`]
     #[inline]
@@ -323,6 +512,25 @@ impl Transform2d {
             z_translation,
         }
     }
+
+    /// Interpolates between `self` and `other` by `t`, returning the resulting [`Transform2d`].
+    ///
+    /// Translation, scale and `z_translation` are interpolated linearly,
+    /// rotation is interpolated along the shortest arc, so interpolating from `170°` to `-170°` passes through `180°`.
+    ///
+    /// When `t` is `0.` the result is `self`, when `t` is `1.` the result is `other`
+    /// (with a rotation that may differ from `other.rotation` by a multiple of a full turn).
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, other: Transform2d, t: f32) -> Self {
+        let rotation_delta = self.rot2().angle_to(other.rot2());
+        Transform2d {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation + rotation_delta * t,
+            scale: self.scale.lerp(other.scale, t),
+            z_translation: self.z_translation + (other.z_translation - self.z_translation) * t,
+        }
+    }
 }
 
 impl From<Transform2d> for Transform {
@@ -347,6 +555,147 @@ impl From<Transform> for Transform2d {
     }
 }
 
+impl From<Transform2d> for Affine2 {
+    #[inline]
+    fn from(transform: Transform2d) -> Self {
+        transform.compute_affine()
+    }
+}
+
+impl From<Transform2d> for Mat3 {
+    #[inline]
+    fn from(transform: Transform2d) -> Self {
+        transform.compute_matrix()
+    }
+}
+
+impl TryFrom<Affine2> for Transform2d {
+    type Error = Transform2dFromAffineError;
+
+    /// Equivalent to [`Transform2d::try_from_affine`].
+    #[inline]
+    fn try_from(affine: Affine2) -> Result<Self, Self::Error> {
+        Transform2d::try_from_affine(affine)
+    }
+}
+
+impl TryFrom<Mat3> for Transform2d {
+    type Error = Transform2dFromAffineError;
+
+    /// Decomposes `matrix` into translation, rotation and scale.
+    ///
+    /// Returns an error if `matrix` is not affine or contains shear.
+    fn try_from(matrix: Mat3) -> Result<Self, Self::Error> {
+        if !matrix.row(2).abs_diff_eq(Vec3::Z, AFFINE_EPSILON) {
+            return Err(Transform2dFromAffineError::NotAffine);
+        }
+        Transform2d::try_from(Affine2::from_mat3(matrix))
+    }
+}
+
+/// The relative tolerance used when decomposing an [`Affine2`] or [`Mat3`] into a [`Transform2d`].
+pub(crate) const AFFINE_EPSILON: f32 = 1e-4;
+
+/// An error returned when an [`Affine2`] or [`Mat3`] can't be represented by a [`Transform2d`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform2dFromAffineError {
+    /// The transform contains shear.
+    Shear {
+        /// The cosine of the angle between the transformed `X` and `Y` axes.
+        ///
+        /// This is `0.` when the axes are perpendicular, and approaches `1.` or `-1.` as the shear grows.
+        shear: f32,
+    },
+    /// The transform collapses space onto a line or a point, so it has no rotation or scale to extract.
+    Degenerate,
+    /// The matrix is not an affine transform, its last row is not `(0, 0, 1)`.
+    NotAffine,
+}
+
+impl fmt::Display for Transform2dFromAffineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Transform2dFromAffineError::Shear { shear } => {
+                write!(
+                    f,
+                    "the transform contains shear, which a Transform2d can't represent \
+                    (the cosine of the angle between its axes is {shear})"
+                )
+            }
+            Transform2dFromAffineError::Degenerate => {
+                write!(f, "the transform is degenerate")
+            }
+            Transform2dFromAffineError::NotAffine => {
+                write!(f, "the matrix is not an affine transform")
+            }
+        }
+    }
+}
+
+impl Error for Transform2dFromAffineError {}
+
+/// An error returned when a [`Transform`] can't be represented by a [`Transform2d`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform2dFromTransformError {
+    /// The transform is rotated out of the `XY` plane.
+    NonPlanar {
+        /// The angle in radians between the local `Z` axis of the transform and the global `Z` axis.
+        tilt: f32,
+    },
+    /// The transform has a `Z` scale other than 1.
+    ScaleZ {
+        /// The `Z` scale of the transform.
+        scale_z: f32,
+    },
+}
+
+impl fmt::Display for Transform2dFromTransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Transform2dFromTransformError::NonPlanar { tilt } => {
+                write!(
+                    f,
+                    "the transform is rotated {tilt} radians out of the XY plane, \
+                    which a Transform2d can't represent"
+                )
+            }
+            Transform2dFromTransformError::ScaleZ { scale_z } => {
+                write!(
+                    f,
+                    "the transform has a Z scale of {scale_z}, which a Transform2d can't represent"
+                )
+            }
+        }
+    }
+}
+
+impl Error for Transform2dFromTransformError {}
+
+impl Mul<Transform2d> for Transform2d {
+    type Output = Transform2d;
+
+    #[inline]
+    fn mul(self, transform: Transform2d) -> Self::Output {
+        self.mul_transform(transform)
+    }
+}
+
+impl MulAssign<Transform2d> for Transform2d {
+    #[inline]
+    fn mul_assign(&mut self, transform: Transform2d) {
+        *self = self.mul_transform(transform);
+    }
+}
+
+impl Mul<Vec2> for Transform2d {
+    type Output = Vec2;
+
+    #[inline]
+    fn mul(self, value: Vec2) -> Self::Output {
+        self.transform_point(value)
+    }
+}
+
 pub trait IntoScale {
     fn into_scale(self) -> Vec2;
 }
@@ -365,10 +714,171 @@ impl IntoScale for f32 {
 
 #[cfg(test)]
 mod tests {
-    use std::f32::consts::TAU;
+    use std::f32::consts::FRAC_PI_2;
 
     use super::*;
 
+    #[test]
+    fn inverse_transform_point() {
+        let transform = Transform2d::from_xy(3., -7.)
+            .with_rotation(TAU / 3.)
+            .with_scale(Vec2::new(2., -0.5));
+        let point = Vec2::new(-1.5, 4.);
+
+        let local = transform.inverse_transform_point(point);
+        assert!(transform.transform_point(local).abs_diff_eq(point, 1e-5));
+        assert!(transform
+            .compute_inverse_affine()
+            .transform_point2(point)
+            .abs_diff_eq(local, 1e-5));
+
+        let vector = Vec2::new(0.25, -2.);
+        assert!(transform
+            .inverse_transform_vector(transform.transform_vector(vector))
+            .abs_diff_eq(vector, 1e-5));
+    }
+
+    #[test]
+    fn inverse() {
+        let transform = Transform2d::from_xyz(3., -7., 2.)
+            .with_rotation(TAU / 5.)
+            .with_scale(Vec2::splat(3.));
+        let identity = transform.mul_transform(transform.try_inverse().unwrap());
+
+        assert!(identity.translation.abs_diff_eq(Vec2::ZERO, 1e-5));
+        assert!(identity.rotation.abs() < 1e-5);
+        assert!(identity.scale.abs_diff_eq(Vec2::ONE, 1e-5));
+        assert_eq!(identity.z_translation, 0.);
+
+        // A non-uniform scale can only be inverted without shear if the rotation is a multiple of 90 degrees.
+        let transform = transform.with_scale(Vec2::new(2., 3.));
+        assert!(matches!(
+            transform.try_inverse(),
+            Err(Transform2dFromAffineError::Shear { .. })
+        ));
+        let transform = transform.with_rotation(FRAC_PI_2);
+        let point = Vec2::new(-1.5, 4.);
+        assert!(transform
+            .try_inverse()
+            .unwrap()
+            .transform_point(transform.transform_point(point))
+            .abs_diff_eq(point, 1e-5));
+        assert!(matches!(
+            Transform2d::from_scale(Vec2::new(0., 1.)).try_inverse(),
+            Err(Transform2dFromAffineError::Degenerate)
+        ));
+    }
+
+    #[test]
+    fn rotate_wraps() {
+        let mut transform = Transform2d::IDENTITY;
+        for _ in 0..100 {
+            transform.rotate(1.);
+        }
+        assert!(transform.rotation.abs() <= TAU / 2.);
+        assert!((transform.rot2().as_radians() - Rot2::radians(100.).as_radians()).abs() < 1e-3);
+
+        let rotation = transform.rotation;
+        transform.rotate_around(Vec2::ZERO, TAU);
+        assert_eq!(transform.rotation, rotation + TAU);
+
+        // Angles and `Rot2`s are accepted alike.
+        let mut a = Transform2d::from_xy(1., 0.);
+        let mut b = a;
+        a.translate_around(Vec2::ZERO, FRAC_PI_2);
+        b.translate_around(Vec2::ZERO, Rot2::radians(FRAC_PI_2));
+        assert!(a.translation.abs_diff_eq(Vec2::Y, 1e-6));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn lerp_shortest_arc() {
+        let start = Transform2d::from_xyz(0., 10., 1.)
+            .with_rotation(Rot2::degrees(170.))
+            .with_scale(Vec2::ONE);
+        let end = Transform2d::from_xyz(10., 0., 3.)
+            .with_rotation(Rot2::degrees(-170.))
+            .with_scale(Vec2::splat(3.));
+
+        let middle = start.lerp(end, 0.5);
+        assert!(middle.translation.abs_diff_eq(Vec2::splat(5.), 1e-5));
+        assert!(middle.scale.abs_diff_eq(Vec2::splat(2.), 1e-5));
+        assert_eq!(middle.z_translation, 2.);
+        assert!((middle.rotation.to_degrees() - 180.).abs() < 1e-3);
+
+        let end = start.lerp(end, 1.);
+        assert!((end.rot2().as_degrees() + 170.).abs() < 1e-3);
+        assert!((start.lerp(start, 0.3).rotation - start.rotation).abs() < 1e-6);
+    }
+
+    #[test]
+    fn try_from_affine_mirrored() {
+        for rotation in [0., 1., -2.5, TAU / 4.] {
+            for scale in [
+                Vec2::new(-2., 3.),
+                Vec2::new(2., -3.),
+                Vec2::new(-2., -3.),
+                Vec2::new(0.5, 4.),
+            ] {
+                let transform = Transform2d::from_xy(1., -1.)
+                    .with_rotation(rotation)
+                    .with_scale(scale);
+                let affine = transform.compute_affine();
+                let decomposed = Transform2d::try_from_affine(affine).unwrap();
+
+                assert!(decomposed.compute_affine().abs_diff_eq(affine, 1e-5));
+                assert!(decomposed.scale.y > 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn operators() {
+        let parent = Transform2d::from_xy(1., 2.).with_rotation(TAU / 8.);
+        let child = Transform2d::from_xy(-3., 0.5).with_scale(Vec2::splat(2.));
+        let point = Vec2::new(4., -1.);
+
+        assert_eq!(parent * child, parent.mul_transform(child));
+        assert_eq!(parent * point, parent.transform_point(point));
+
+        let mut transform = parent;
+        transform *= child;
+        assert_eq!(transform, parent * child);
+    }
+
+    #[test]
+    fn affine_conversions() {
+        let transform = Transform2d::from_xy(5., -2.)
+            .with_rotation(TAU / 6.)
+            .with_scale(Vec2::new(2., 0.5));
+
+        let from_affine = Transform2d::try_from(Affine2::from(transform)).unwrap();
+        assert!(from_affine
+            .translation
+            .abs_diff_eq(transform.translation, 1e-5));
+        assert!((from_affine.rotation - transform.rotation).abs() < 1e-5);
+        assert!(from_affine.scale.abs_diff_eq(transform.scale, 1e-5));
+
+        let from_matrix = Transform2d::try_from(Mat3::from(transform)).unwrap();
+        assert!(from_matrix
+            .translation
+            .abs_diff_eq(transform.translation, 1e-5));
+
+        let shear = Affine2::from_cols(Vec2::X, Vec2::new(1., 1.), Vec2::ZERO);
+        let Err(Transform2dFromAffineError::Shear { shear }) = Transform2d::try_from(shear) else {
+            panic!("shear was not detected");
+        };
+        assert!((shear - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+        assert_eq!(
+            Transform2d::try_from(Affine2::from_scale(Vec2::new(1., 0.))),
+            Err(Transform2dFromAffineError::Degenerate)
+        );
+        assert_eq!(
+            Transform2d::try_from(Mat3::from_cols(Vec3::new(1., 0., 1.), Vec3::Y, Vec3::Z)),
+            Err(Transform2dFromAffineError::NotAffine)
+        );
+    }
+
     #[test]
     fn local_vectors() {
         let mut transform = Transform2d::from_rotation(TAU / 2.44);
@@ -378,4 +888,30 @@ mod tests {
         assert_eq!(transform.local_y(), transform.rotation_matrix() * Vec2::Y);
         assert_eq!(transform.local_x(), transform.rotation_matrix() * Vec2::X);
     }
+
+    #[test]
+    fn try_from_transform() {
+        let planar = Transform::from_xyz(1., 2., 3.).with_rotation(Quat::from_rotation_z(1.));
+        assert_eq!(
+            Transform2d::try_from_transform(&planar),
+            Ok(Transform2d::from(planar))
+        );
+
+        let tilted = planar.with_rotation(Quat::from_rotation_z(1.) * Quat::from_rotation_x(0.5));
+        let Err(Transform2dFromTransformError::NonPlanar { tilt }) =
+            Transform2d::try_from_transform(&tilted)
+        else {
+            panic!("expected a non-planar error");
+        };
+        assert!((tilt - 0.5).abs() < 1e-5);
+
+        let projected = Transform2d::from_transform_projected(&tilted);
+        assert!((projected.rotation - 1.).abs() < 1e-5);
+        assert_eq!(projected.translation, Vec2::new(1., 2.));
+
+        assert_eq!(
+            Transform2d::try_from_transform(&planar.with_scale(Vec3::new(1., 1., 2.))),
+            Err(Transform2dFromTransformError::ScaleZ { scale_z: 2. })
+        );
+    }
 }
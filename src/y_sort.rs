@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+
+/// Draws an [`Entity`] in front of entities that are higher up on the screen, for top-down and isometric scenes.
+///
+/// A z is computed from the world-space `Y` of the entity using the [`YSortConfig`] resource,
+/// and added to the `z_translation` of its [`Transform2d`](crate::transform2d::Transform2d).
+/// The [`Transform2d`](crate::transform2d::Transform2d) itself is left untouched,
+/// the z is added to the [`GlobalTransform2d`](crate::global_transform2d::GlobalTransform2d) and the [`Transform`] used for rendering.
+/// Children inherit the z of a sorted parent, so they are drawn along with it.
+/// A child with its own [`YSort`] replaces the z of its sorted parent with its own, instead of adding to it.
+#[derive(Component, Debug, Default, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct YSort {
+    /// Added to the world-space `Y` before sorting, for example to sort by the feet of a character instead of its center.
+    pub offset: f32,
+}
+
+impl YSort {
+    /// Creates a new [`YSort`] with a sort `offset`.
+    #[inline]
+    pub const fn new(offset: f32) -> Self {
+        YSort { offset }
+    }
+}
+
+/// The range of world-space `Y` sorted by [`YSort`], and the range of z it's mapped to.
+#[derive(Resource, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct YSortConfig {
+    /// The lowest world-space `Y` that's sorted. Entities below this are drawn at `depth`.
+    pub min_y: f32,
+    /// The highest world-space `Y` that's sorted. Entities above this are drawn at `0.`.
+    pub max_y: f32,
+    /// The range of z the sorted `Y` is mapped to.
+    ///
+    /// Keep this below [`ZOrderConfig::layer_depth`](crate::z_order::ZOrderConfig::layer_depth) to keep sorted entities inside their layer.
+    pub depth: f32,
+}
+
+impl Default for YSortConfig {
+    fn default() -> Self {
+        YSortConfig {
+            min_y: -10_000.,
+            max_y: 10_000.,
+            depth: 0.5,
+        }
+    }
+}
+
+impl YSortConfig {
+    /// Returns the z added to an entity at the world-space `y`.
+    #[inline]
+    pub fn z_at(&self, y: f32) -> f32 {
+        let t = ((y - self.min_y) / (self.max_y - self.min_y)).clamp(0., 1.);
+        (1. - t) * self.depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn z_at() {
+        let config = YSortConfig {
+            min_y: -100.,
+            max_y: 100.,
+            depth: 1.,
+        };
+
+        assert_eq!(config.z_at(-100.), 1.);
+        assert_eq!(config.z_at(0.), 0.5);
+        assert_eq!(config.z_at(100.), 0.);
+        assert_eq!(config.z_at(500.), 0.);
+        assert!(config.z_at(-10.) > config.z_at(10.));
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+
+/// Describes the draw order of an [`Entity`] as a layer and an order within that layer,
+/// instead of a raw [`Transform2d::z_translation`](crate::transform2d::Transform2d::z_translation).
+///
+/// This component acts as a proxy to the `z_translation` of the [`Transform2d`](crate::transform2d::Transform2d) component, and thus *requires* that a [`Transform2d`](crate::transform2d::Transform2d) is present to function.
+/// The `z_translation` is computed from the layer and sub-order using the [`ZOrderConfig`] resource.
+///
+/// The layer can be any type that converts into an `i32`, like a fieldless enum of the layers in your game:
+/// ```
+/// # use bevy_mod_transform2d::z_order::ZOrder;
+/// #[derive(Clone, Copy)]
+/// enum Layer {
+///     Background,
+///     World,
+///     Ui,
+/// }
+///
+/// impl From<Layer> for i32 {
+///     fn from(layer: Layer) -> i32 {
+///         layer as i32
+///     }
+/// }
+///
+/// let z_order = ZOrder::from_layer(Layer::World).with_sub_order(2);
+/// ```
+///
+/// Like `z_translation`, the z of a child is added to the z of its parent.
+/// In debug builds a warning is logged when that moves an entity out of its own layer.
+#[derive(Component, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct ZOrder {
+    /// The layer. Entities on higher layers are drawn in front of entities on lower layers.
+    pub layer: i32,
+    /// The order within the layer. Entities with a higher sub-order are drawn in front.
+    pub sub_order: i32,
+}
+
+impl ZOrder {
+    /// Creates a new [`ZOrder`] on `layer`, with a sub-order of `0`.
+    #[inline]
+    pub fn from_layer(layer: impl Into<i32>) -> Self {
+        ZOrder {
+            layer: layer.into(),
+            sub_order: 0,
+        }
+    }
+
+    /// Returns this [`ZOrder`] with a new sub-order.
+    #[must_use]
+    #[inline]
+    pub const fn with_sub_order(mut self, sub_order: i32) -> Self {
+        self.sub_order = sub_order;
+        self
+    }
+
+    /// Returns the `z_translation` for this [`ZOrder`].
+    #[inline]
+    pub fn z_translation(&self, config: &ZOrderConfig) -> f32 {
+        self.layer as f32 * config.layer_depth + self.sub_order as f32 * config.sub_order_depth
+    }
+}
+
+/// The spacing along the `Z` axis used to compute the `z_translation` of a [`ZOrder`].
+#[derive(Resource, Debug, PartialEq, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct ZOrderConfig {
+    /// The distance between two layers.
+    pub layer_depth: f32,
+    /// The distance between two sub-orders within a layer.
+    ///
+    /// Sub-orders stay inside their layer as long as `sub_order * sub_order_depth` is less than `layer_depth`.
+    pub sub_order_depth: f32,
+}
+
+impl Default for ZOrderConfig {
+    fn default() -> Self {
+        ZOrderConfig {
+            layer_depth: 1.,
+            sub_order_depth: 0.001,
+        }
+    }
+}
+
+impl ZOrderConfig {
+    /// Returns the layer that contains the z `z`.
+    #[inline]
+    pub fn layer_at(&self, z: f32) -> i32 {
+        // Rounded slightly, so the z of a layer isn't put in the layer below by floating point error.
+        (z / self.layer_depth + 1e-4).floor() as i32
+    }
+}
+
+/// Whether the `z_translation` of an [`Entity`] is relative to its [`Parent`] or absolute.
+///
+/// Entities without this component are [`ZMode::Relative`].
+#[derive(Component, Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, PartialEq, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum ZMode {
+    /// The `z_translation` is added to the z of the [`Parent`].
+    #[default]
+    Relative,
+    /// The `z_translation` is the global z, regardless of the [`Parent`].
+    ///
+    /// The translation along the `X` and `Y` axes still follows the [`Parent`].
+    /// Use this for overlays that are children of world entities but must be drawn on top.
+    ///
+    /// The [`Transform`] of the entity is relative to the [`Parent`], like Bevy expects,
+    /// and [`sync_transform_3d_to_2d`](crate::systems::sync_transform_3d_to_2d) converts it back into the absolute z.
+    Absolute,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn z_translation() {
+        let config = ZOrderConfig::default();
+
+        let z_order = ZOrder::from_layer(3).with_sub_order(5);
+        assert!((z_order.z_translation(&config) - 3.005).abs() < 1e-6);
+        assert_eq!(config.layer_at(z_order.z_translation(&config)), 3);
+        assert_eq!(
+            config.layer_at(ZOrder::from_layer(-2).z_translation(&config)),
+            -2
+        );
+        assert_eq!(config.layer_at(-1.5), -2);
+    }
+}